@@ -0,0 +1,73 @@
+use eframe::NativeOptions;
+use egui::emath::easing::quadratic_out;
+use egui::{RichText, vec2};
+use egui_animate::{Animation, after, animate, presets};
+
+/// The distance to slide in.
+const SLIDE_DISTANCE: f32 = 10.0;
+const ANIM_DURATION: f32 = 0.4;
+
+/// Fade and slide in from below.
+const PRESENT: Animation = Animation::new_in(ANIM_DURATION, |ui, normal| {
+    let normal = quadratic_out(normal);
+
+    ui.set_opacity(normal);
+    presets::slide(ui, vec2(0.0, SLIDE_DISTANCE * (1.0 - normal)));
+});
+
+#[derive(Default)]
+struct ChainApp {
+    show_title: bool,
+    show_body: bool,
+    show_footer: bool,
+}
+
+impl eframe::App for ChainApp {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.heading("Chain Example");
+            ui.label("This example demonstrates:");
+            ui.label("• Staging animations across elements with 'after'");
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                if ui.button("Present").clicked() {
+                    self.show_title = true;
+                }
+                if ui.button("Reset").clicked() {
+                    *self = ChainApp::default();
+                }
+            });
+
+            animate(ui, "title_anim", self.show_title, PRESENT, |ui, show| {
+                if show {
+                    ui.label(RichText::new("Title").size(32.0));
+                }
+            });
+            // Present the body once the title is presented.
+            after(ui, "title_anim", || self.show_body = self.show_title);
+
+            animate(ui, "body_anim", self.show_body, PRESENT, |ui, show| {
+                if show {
+                    ui.label("The body, presented after the title.");
+                }
+            });
+            // Present the footer once the body is presented.
+            after(ui, "body_anim", || self.show_footer = self.show_body);
+
+            animate(ui, "footer_anim", self.show_footer, PRESENT, |ui, show| {
+                if show {
+                    ui.weak("The footer, presented last.");
+                }
+            });
+        });
+    }
+}
+
+fn main() -> eframe::Result {
+    eframe::run_native(
+        "Chain Example",
+        NativeOptions::default(),
+        Box::new(|_| Ok(Box::<ChainApp>::default())),
+    )
+}
@@ -1,68 +1,47 @@
 use std::fmt::Display;
 
 use eframe::NativeOptions;
-use egui::emath::TSTransform;
 use egui::emath::easing::{quadratic_in, quadratic_out};
-use egui::{Button, Label};
-use egui_animate::{Animation, animate};
+use egui::{Button, Label, vec2};
+use egui_animate::{Animation, Cycle, animate, animation, cycle, presets};
 
 /// The distance to slide out/in.
 const SLIDE_DISTANCE: f32 = 10.0;
 const ANIM_DURATION: f32 = 0.3;
 
 /// The menu forward animation.
-mod forward {
-    use super::*;
-
-    fn out_fn(ui: &mut egui::Ui, normal: f32) {
+const FORWARD: Animation = animation! {
+    out: |ui, normal| {
         let normal = quadratic_in(normal);
 
         ui.set_opacity(1.0 - normal);
-        ui.ctx().set_transform_layer(
-            ui.layer_id(),
-            TSTransform::from_translation((normal as f32 * -SLIDE_DISTANCE, 0.0).into()),
-        );
-    }
-    fn in_fn(ui: &mut egui::Ui, normal: f32) {
+        presets::slide(ui, vec2(normal * -SLIDE_DISTANCE, 0.0));
+    },
+    in: |ui, normal| {
         let normal = quadratic_out(normal);
 
         ui.set_opacity(normal);
-        ui.ctx().set_transform_layer(
-            ui.layer_id(),
-            TSTransform::from_translation(
-                (SLIDE_DISTANCE + normal as f32 * -SLIDE_DISTANCE, 0.0).into(),
-            ),
-        );
-    }
-    pub const ANIMATION: Animation = Animation::new(ANIM_DURATION, out_fn, in_fn);
-}
+        presets::slide(ui, vec2(SLIDE_DISTANCE + normal * -SLIDE_DISTANCE, 0.0));
+    },
+    duration: ANIM_DURATION,
+};
 
 /// The menu back animation.
-mod back {
-    use super::*;
-
-    fn out_fn(ui: &mut egui::Ui, normal: f32) {
+const BACK: Animation = animation! {
+    out: |ui, normal| {
         let normal = quadratic_in(normal);
 
         ui.set_opacity(1.0 - normal);
-        ui.ctx().set_transform_layer(
-            ui.layer_id(),
-            TSTransform::from_translation((normal as f32 * SLIDE_DISTANCE, 0.0).into()),
-        );
-    }
-    fn in_fn(ui: &mut egui::Ui, normal: f32) {
+        presets::slide(ui, vec2(normal * SLIDE_DISTANCE, 0.0));
+    },
+    in: |ui, normal| {
         let normal = quadratic_out(normal);
 
         ui.set_opacity(normal);
-        ui.ctx().set_transform_layer(
-            ui.layer_id(),
-            TSTransform::from_translation(
-                (-SLIDE_DISTANCE + normal as f32 * SLIDE_DISTANCE, 0.0).into(),
-            ),
-        );
-    }
-    pub const ANIMATION: Animation = Animation::new(ANIM_DURATION, out_fn, in_fn);
-}
+        presets::slide(ui, vec2(-SLIDE_DISTANCE + normal * SLIDE_DISTANCE, 0.0));
+    },
+    duration: ANIM_DURATION,
+};
 
 struct MenuApp {
     anim: Animation,
@@ -74,7 +53,7 @@ struct MenuApp {
 impl Default for MenuApp {
     fn default() -> Self {
         MenuApp {
-            anim: forward::ANIMATION,
+            anim: FORWARD,
             menu_state: MenuState::default(),
             opt1_state: OptionState::Red,
             opt2_state: OptionState::Red,
@@ -84,7 +63,7 @@ impl Default for MenuApp {
 
 impl eframe::App for MenuApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        egui::CentralPanel::default().show(&ctx, |ui| {
+        egui::CentralPanel::default().show(ctx, |ui| {
             ui.heading("Menu Example");
             ui.label("This example demonstrates:");
             ui.label("• Animating an entire ui scope");
@@ -100,11 +79,11 @@ impl eframe::App for MenuApp {
                 |ui, menu| match menu {
                     MenuState::MainMenu => {
                         if ui.button("New Game").clicked() {
-                            self.anim = forward::ANIMATION;
+                            self.anim = FORWARD;
                             self.menu_state = MenuState::NewGame;
                         }
                         if ui.button("Options").clicked() {
-                            self.anim = forward::ANIMATION;
+                            self.anim = FORWARD;
                             self.menu_state = MenuState::Options;
                         }
                         ui.add_enabled(false, Button::new("Quit"));
@@ -112,41 +91,29 @@ impl eframe::App for MenuApp {
                     MenuState::NewGame => {
                         ui.add_enabled(false, Button::new("Start Game"));
                         if ui.button("Back").clicked() {
-                            self.anim = back::ANIMATION;
+                            self.anim = BACK;
                             self.menu_state = MenuState::MainMenu;
                         }
                     }
                     MenuState::Options => {
                         ui.horizontal(|ui| {
                             ui.label("Option 1");
-                            animate(
-                                ui,
-                                "opt1_anim",
-                                self.opt1_state,
-                                forward::ANIMATION,
-                                |ui, opt| {
-                                    if ui.button(format!("{}", opt)).clicked() {
-                                        self.opt1_state.next();
-                                    }
-                                },
-                            );
+                            animate(ui, "opt1_anim", self.opt1_state, FORWARD, |ui, opt| {
+                                if ui.button(format!("{}", opt)).clicked() {
+                                    self.opt1_state.next();
+                                }
+                            });
                         });
                         ui.horizontal(|ui| {
                             ui.label("Option 2");
-                            animate(
-                                ui,
-                                "opt2_anim",
-                                self.opt2_state,
-                                forward::ANIMATION,
-                                |ui, opt| {
-                                    if ui.button(format!("{}", opt)).clicked() {
-                                        self.opt2_state.next();
-                                    }
-                                },
-                            );
+                            animate(ui, "opt2_anim", self.opt2_state, FORWARD, |ui, opt| {
+                                if ui.button(format!("{}", opt)).clicked() {
+                                    self.opt2_state.next();
+                                }
+                            });
                         });
                         if ui.button("Back").clicked() {
-                            self.anim = back::ANIMATION;
+                            self.anim = BACK;
                             self.menu_state = MenuState::Confirm;
                         }
                     }
@@ -155,11 +122,11 @@ impl eframe::App for MenuApp {
                         ui.add_enabled(false, Label::new("(This does nothing)"));
 
                         if ui.button("Yes").clicked() {
-                            self.anim = back::ANIMATION;
+                            self.anim = BACK;
                             self.menu_state = MenuState::MainMenu;
                         }
                         if ui.button("No").clicked() {
-                            self.anim = back::ANIMATION;
+                            self.anim = BACK;
                             self.menu_state = MenuState::MainMenu;
                         }
                     }
@@ -178,12 +145,14 @@ enum MenuState {
     Confirm,
 }
 
-#[derive(Default, Clone, Copy, PartialEq)]
-enum OptionState {
-    #[default]
-    Red,
-    Green,
-    Blue,
+cycle! {
+    #[derive(Default, Clone, Copy, PartialEq)]
+    enum OptionState {
+        #[default]
+        Red,
+        Green,
+        Blue,
+    }
 }
 
 impl Display for OptionState {
@@ -200,16 +169,6 @@ impl Display for OptionState {
     }
 }
 
-impl OptionState {
-    fn next(&mut self) {
-        match self {
-            OptionState::Red => *self = OptionState::Green,
-            OptionState::Green => *self = OptionState::Blue,
-            OptionState::Blue => *self = OptionState::Red,
-        }
-    }
-}
-
 fn main() -> eframe::Result {
     eframe::run_native(
         "Menu Example",
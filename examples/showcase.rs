@@ -1,8 +1,10 @@
 use eframe::NativeOptions;
-use egui::emath::TSTransform;
 use egui::emath::easing::{quadratic_in, quadratic_out};
-use egui::{InnerResponse, RichText};
-use egui_animate::{Animation, AnimationSegment, animate};
+use egui::{InnerResponse, RichText, vec2};
+use egui_animate::presets::ColorSpace;
+use egui_animate::{
+    Animation, AnimationSegment, animate, debug_draw, presets, reverse, set_debug_draw,
+};
 
 /// The distance to slide out/in.
 const SLIDE_DISTANCE: f32 = 10.0;
@@ -16,58 +18,16 @@ mod fade {
     }
 }
 
-mod slide_left {
-    use super::*;
-
-    pub fn out_fn(ui: &mut egui::Ui, normal: f32) {
-        ui.ctx().set_transform_layer(
-            ui.layer_id(),
-            TSTransform::from_translation((normal as f32 * -SLIDE_DISTANCE, 0.0).into()),
-        );
-    }
-    pub fn in_fn(ui: &mut egui::Ui, normal: f32) {
-        ui.ctx().set_transform_layer(
-            ui.layer_id(),
-            TSTransform::from_translation(
-                (SLIDE_DISTANCE + normal as f32 * -SLIDE_DISTANCE, 0.0).into(),
-            ),
-        );
-    }
-}
-
-mod slide_right {
-    use super::*;
-
-    pub fn out_fn(ui: &mut egui::Ui, normal: f32) {
-        ui.ctx().set_transform_layer(
-            ui.layer_id(),
-            TSTransform::from_translation((normal as f32 * SLIDE_DISTANCE, 0.0).into()),
-        );
-    }
-    pub fn in_fn(ui: &mut egui::Ui, normal: f32) {
-        ui.ctx().set_transform_layer(
-            ui.layer_id(),
-            TSTransform::from_translation(
-                (-SLIDE_DISTANCE + normal as f32 * SLIDE_DISTANCE, 0.0).into(),
-            ),
-        );
-    }
-}
-
 mod slide_fade_ease_left {
     use super::*;
 
     pub fn out_fn(ui: &mut egui::Ui, normal: f32) {
         let normal = quadratic_in(normal);
-
-        fade::out_fn(ui, normal);
-        slide_left::out_fn(ui, normal);
+        presets::slide_fade(ui, 1.0 - normal, vec2(-SLIDE_DISTANCE, 0.0));
     }
     pub fn in_fn(ui: &mut egui::Ui, normal: f32) {
         let normal = quadratic_out(normal);
-
-        fade::in_fn(ui, normal);
-        slide_left::in_fn(ui, normal);
+        presets::slide_fade(ui, normal, vec2(SLIDE_DISTANCE, 0.0));
     }
 }
 
@@ -76,52 +36,19 @@ mod slide_fade_ease_right {
 
     pub fn out_fn(ui: &mut egui::Ui, normal: f32) {
         let normal = quadratic_in(normal);
-        fade::out_fn(ui, normal);
-        slide_right::out_fn(ui, normal);
+        presets::slide_fade(ui, 1.0 - normal, vec2(SLIDE_DISTANCE, 0.0));
     }
     pub fn in_fn(ui: &mut egui::Ui, normal: f32) {
         let normal = quadratic_out(normal);
-        fade::in_fn(ui, normal);
-        slide_right::in_fn(ui, normal);
-    }
-}
-
-mod clip_width {
-    pub fn out_fn(ui: &mut egui::Ui, normal: f32) {
-        in_fn(ui, 1.0 - normal);
-    }
-    pub fn in_fn(ui: &mut egui::Ui, normal: f32) {
-        let mut rect = ui.clip_rect();
-        rect.set_width(rect.width() * normal);
-        ui.set_clip_rect(rect);
-    }
-}
-
-mod clip_height {
-    pub fn out_fn(ui: &mut egui::Ui, normal: f32) {
-        in_fn(ui, 1.0 - normal);
-    }
-    pub fn in_fn(ui: &mut egui::Ui, normal: f32) {
-        let mut rect = ui.clip_rect();
-        rect.set_height(rect.height() * normal);
-        ui.set_clip_rect(rect);
+        presets::slide_fade(ui, normal, vec2(-SLIDE_DISTANCE, 0.0));
     }
 }
 
 mod fade_green {
     use super::*;
 
-    pub fn out_fn(ui: &mut egui::Ui, normal: f32) {
-        in_fn(ui, 1.0 - normal);
-    }
     pub fn in_fn(ui: &mut egui::Ui, normal: f32) {
-        let inverse_normal = 1.0 - normal as f32;
-
-        let mut text_color = ui.visuals_mut().text_color();
-        let red_color_range = (255 - text_color[1]) as f32;
-        text_color[1] += (red_color_range * inverse_normal).min(255.0) as u8;
-        ui.visuals_mut().override_text_color = Some(text_color);
-        ui.set_opacity(normal);
+        presets::tint_text(ui, 1.0 - normal, egui::Color32::GREEN, ColorSpace::Linear);
 
         fade::in_fn(ui, normal);
     }
@@ -130,17 +57,8 @@ mod fade_green {
 mod fade_red {
     use super::*;
 
-    pub fn out_fn(ui: &mut egui::Ui, normal: f32) {
-        in_fn(ui, 1.0 - normal);
-    }
     pub fn in_fn(ui: &mut egui::Ui, normal: f32) {
-        let inverse_normal = 1.0 - normal as f32;
-
-        let mut text_color = ui.visuals_mut().text_color();
-        let red_color_range = (255 - text_color[0]) as f32;
-        text_color[0] += (red_color_range * inverse_normal).min(255.0) as u8;
-        ui.visuals_mut().override_text_color = Some(text_color);
-        ui.set_opacity(normal);
+        presets::tint_text(ui, 1.0 - normal, egui::Color32::RED, ColorSpace::Linear);
 
         fade::in_fn(ui, normal);
     }
@@ -202,10 +120,10 @@ impl AnimationType {
             AnimationType::Fade => fade::out_fn,
             AnimationType::SlideFadeEaseLeft => slide_fade_ease_left::out_fn,
             AnimationType::SlideFadeEaseRight => slide_fade_ease_right::out_fn,
-            AnimationType::ClipWidth => clip_width::out_fn,
-            AnimationType::ClipHeight => clip_height::out_fn,
-            AnimationType::FadeRed => fade_red::out_fn,
-            AnimationType::FadeGreen => fade_green::out_fn,
+            AnimationType::ClipWidth => reverse!(presets::clip_width),
+            AnimationType::ClipHeight => reverse!(presets::clip_height),
+            AnimationType::FadeRed => reverse!(fade_red::in_fn),
+            AnimationType::FadeGreen => reverse!(fade_green::in_fn),
         }
     }
 
@@ -214,8 +132,8 @@ impl AnimationType {
             AnimationType::Fade => fade::in_fn,
             AnimationType::SlideFadeEaseLeft => slide_fade_ease_left::in_fn,
             AnimationType::SlideFadeEaseRight => slide_fade_ease_right::in_fn,
-            AnimationType::ClipWidth => clip_width::in_fn,
-            AnimationType::ClipHeight => clip_height::in_fn,
+            AnimationType::ClipWidth => presets::clip_width,
+            AnimationType::ClipHeight => presets::clip_height,
             AnimationType::FadeRed => fade_red::in_fn,
             AnimationType::FadeGreen => fade_green::in_fn,
         }
@@ -255,15 +173,9 @@ impl Default for ShowcaseApp {
 
 impl ShowcaseApp {
     /// Create an `Animation` from given configuration.
-    fn into_anim(&self) -> Animation {
-        let out_seg = AnimationSegment {
-            duration: self.out_dur,
-            anim_fn: self.out_anim.out_fn(),
-        };
-        let in_seg = AnimationSegment {
-            duration: self.in_dur,
-            anim_fn: self.in_anim.in_fn(),
-        };
+    fn to_anim(&self) -> Animation {
+        let out_seg = AnimationSegment::new(self.out_dur, self.out_anim.out_fn());
+        let in_seg = AnimationSegment::new(self.in_dur, self.in_anim.in_fn());
         Animation::from_segments(out_seg, in_seg)
     }
 }
@@ -278,6 +190,14 @@ impl eframe::App for ShowcaseApp {
             ui.label("• Various example animations");
             ui.separator();
 
+            let mut debug = debug_draw(ctx);
+            if ui
+                .checkbox(&mut debug, "Debug draw animation layers")
+                .changed()
+            {
+                set_debug_draw(ctx, debug);
+            }
+
             ui.group(|ui| {
                 ui.label("Animation for the prior value before transition");
                 ui.add(egui::Slider::new(&mut self.out_dur, 0.0..=2.0).text("Duration"));
@@ -302,7 +222,7 @@ impl eframe::App for ShowcaseApp {
                 ui,
                 "int_anim",
                 self.value_state,
-                self.into_anim(),
+                self.to_anim(),
                 |ui, value| {
                     let text = RichText::new(format!("Int: {}", value)).size(48.0);
                     ui.label(text);
@@ -315,10 +235,10 @@ impl eframe::App for ShowcaseApp {
 
                     ui.horizontal(|ui| {
                         if ui.button("Decrement").clicked() {
-                            self.value_state = value.checked_sub(1).unwrap_or(0);
+                            self.value_state = value.saturating_sub(1);
                         };
                         if ui.button("Increment").clicked() {
-                            self.value_state = value.checked_add(1).unwrap_or(u8::MAX);
+                            self.value_state = value.saturating_add(1);
                         };
                     });
                 },
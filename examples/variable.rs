@@ -14,7 +14,7 @@ mod increment {
 
     fn out_fn(ui: &mut egui::Ui, normal: f32) {
         let normal = quadratic_in(normal);
-        let inverse_normal = 1.0 - normal as f32;
+        let inverse_normal = 1.0 - normal;
 
         let mut text_color = ui.visuals_mut().text_color();
         text_color[1] = 255;
@@ -23,7 +23,7 @@ mod increment {
         ui.set_opacity(inverse_normal);
         ui.ctx().set_transform_layer(
             ui.layer_id(),
-            TSTransform::from_translation((0.0, normal as f32 * -SLIDE_DISTANCE).into()),
+            TSTransform::from_translation((0.0, normal * -SLIDE_DISTANCE).into()),
         );
     }
     fn in_fn(ui: &mut egui::Ui, normal: f32) {
@@ -32,9 +32,7 @@ mod increment {
         ui.set_opacity(normal);
         ui.ctx().set_transform_layer(
             ui.layer_id(),
-            TSTransform::from_translation(
-                (0.0, SLIDE_DISTANCE + normal as f32 * -SLIDE_DISTANCE).into(),
-            ),
+            TSTransform::from_translation((0.0, SLIDE_DISTANCE + normal * -SLIDE_DISTANCE).into()),
         );
     }
     pub const ANIMATION: Animation = Animation::new(ANIM_DURATION, out_fn, in_fn);
@@ -46,7 +44,7 @@ mod decrement {
 
     fn out_fn(ui: &mut egui::Ui, normal: f32) {
         let normal = quadratic_in(normal);
-        let inverse_normal = 1.0 - normal as f32;
+        let inverse_normal = 1.0 - normal;
 
         let mut text_color = ui.visuals_mut().text_color();
         text_color[0] = 255;
@@ -55,7 +53,7 @@ mod decrement {
         ui.set_opacity(inverse_normal);
         ui.ctx().set_transform_layer(
             ui.layer_id(),
-            TSTransform::from_translation((0.0, normal as f32 * SLIDE_DISTANCE).into()),
+            TSTransform::from_translation((0.0, normal * SLIDE_DISTANCE).into()),
         );
     }
     fn in_fn(ui: &mut egui::Ui, normal: f32) {
@@ -64,9 +62,7 @@ mod decrement {
 
         ui.ctx().set_transform_layer(
             ui.layer_id(),
-            TSTransform::from_translation(
-                (0.0, -SLIDE_DISTANCE + normal as f32 * SLIDE_DISTANCE).into(),
-            ),
+            TSTransform::from_translation((0.0, -SLIDE_DISTANCE + normal * SLIDE_DISTANCE).into()),
         );
     }
     pub const ANIMATION: Animation = Animation::new(ANIM_DURATION, out_fn, in_fn);
@@ -88,7 +84,7 @@ impl Default for VariableApp {
 
 impl eframe::App for VariableApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        egui::CentralPanel::default().show(&ctx, |ui| {
+        egui::CentralPanel::default().show(ctx, |ui| {
             ui.heading("Variable Example");
             ui.label("This example demonstrates:");
             ui.label("• Animating a single ui element");
@@ -0,0 +1,98 @@
+//! Accessibility of running animations.
+use crate::mem;
+
+/// Enable or disable hiding the contents of running animations from assistive
+/// technologies (such as screen readers) for the given [`egui::Context`]. Disabled by
+/// default.
+///
+/// When enabled, the scoped [`egui::Ui`] of each running animation is marked as hidden
+/// in the accessibility tree, so transient, half-visible contents are not read
+/// mid-transition. The contents are exposed again once the animation settles. Requires
+/// the `accesskit` feature, and does nothing otherwise.
+///
+/// Hiding applies for the entire animation, including holds. Long animations hide
+/// contents for longer, which may itself confuse assistive technologies; prefer short
+/// animations when enabled. Contents are hidden from assistive technologies only, and
+/// remain interactive.
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// # let ctx = egui::Context::default();
+/// set_hide_while_animating(&ctx, true);
+/// ```
+pub fn set_hide_while_animating(ctx: &egui::Context, enabled: bool) {
+    mem::set_hide_while_animating(ctx, enabled);
+}
+
+/// Returns `true` if the contents of running animations are hidden from assistive
+/// technologies for the given [`egui::Context`]. See [`set_hide_while_animating`].
+pub fn hide_while_animating(ctx: &egui::Context) -> bool {
+    mem::get_hide_while_animating(ctx)
+}
+
+/// Wrap the `add_contents` of a running animation, hiding the scoped [`egui::Ui`] from
+/// assistive technologies if enabled.
+pub(crate) fn with_accessibility<R>(
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> impl FnOnce(&mut egui::Ui) -> R {
+    move |ui| {
+        #[cfg(feature = "accesskit")]
+        if hide_while_animating(ui.ctx()) {
+            ui.ctx()
+                .accesskit_node_builder(ui.unique_id(), |node| node.set_hidden());
+        }
+        add_contents(ui)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hide_while_animating() {
+        let ctx = egui::Context::default();
+
+        assert!(!hide_while_animating(&ctx));
+        set_hide_while_animating(&ctx, true);
+        assert!(hide_while_animating(&ctx));
+    }
+
+    #[cfg(feature = "accesskit")]
+    #[test]
+    fn test_hidden_node() {
+        use crate::{Animation, animate};
+
+        const TEST_ANIM: Animation = Animation::new(1.0, |_, _| {}, |_, _| {});
+        let ctx = egui::Context::default();
+        ctx.enable_accesskit();
+        set_hide_while_animating(&ctx, true);
+        let is_hidden = |time: f64, value: i32| {
+            let input = egui::RawInput {
+                time: Some(time),
+                ..Default::default()
+            };
+            let mut scope_id = egui::Id::NULL;
+            let output = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    animate(ui, "test_anim", value, TEST_ANIM, |ui, _| {
+                        scope_id = ui.unique_id();
+                        ui.label("Contents");
+                    });
+                });
+            });
+            let update = output.platform_output.accesskit_update.unwrap();
+            update
+                .nodes
+                .iter()
+                .find(|(node_id, _)| node_id.0 == scope_id.value())
+                .is_some_and(|(_, node)| node.is_hidden())
+        };
+
+        assert!(!is_hidden(0.0, 0));
+        assert!(is_hidden(1.0, 1));
+        assert!(is_hidden(1.75, 1));
+        assert!(!is_hidden(2.0, 1));
+    }
+}
@@ -1,3 +1,8 @@
+use std::hash::{Hash, Hasher};
+
+use crate::Secs;
+use crate::mem;
+
 /// An animation defined by out-in [`AnimationSegment`](s).
 ///
 /// An animation must include either an *out* function, an *in* function, or both.
@@ -19,15 +24,16 @@
 ///
 /// Mutating functions recieve a *normal*, representing the linear relative
 /// progress (`0.0` to `1.0`) of the animation segment. This can be passed to
-/// easing functions for smoother animations, and often reversed to reverse a
-/// previously defined animation.
+/// easing functions for smoother animations.
+///
+/// By convention, the *normal* of both segments progresses forward, while the contents
+/// progress from shown to hidden during the *out* segment, and from hidden to shown
+/// during the *in* segment. An *in* function is therefore typically written for the
+/// shown state at `1.0`, and reversed with [`reverse!`](crate::reverse) to define the
+/// matching *out* function.
 ///
 /// ```
-/// # use egui_animate::Animation;
-/// fn out_fn(ui: &mut egui::Ui, normal: f32) {
-///     // Reverse the normal (1.0 to 0.0 progression), and pass to the `in_fn`.
-///     in_fn(ui, 1.0 - normal);
-/// };
+/// # use egui_animate::*;
 /// fn in_fn(ui: &mut egui::Ui, normal: f32) {
 ///     // Apply easing to the normal.
 ///     let normal = egui::emath::easing::quadratic_out(normal);
@@ -35,14 +41,85 @@
 ///     ui.set_opacity(normal);
 /// };
 ///
-/// const FADE_ANIM: Animation = Animation::new(0.2, out_fn, in_fn);
+/// // Reverse the normal (1.0 to 0.0 progression) for the out segment.
+/// const FADE_ANIM: Animation = Animation::new(0.2, reverse!(in_fn), in_fn);
 /// ```
-#[derive(Default, Clone, Copy)]
+///
+/// # Equality
+///
+/// Animations implement `Eq` and `Hash`, comparing durations bitwise and animation
+/// functions by address. Function addresses are not guaranteed to be unique: identical
+/// functions may be merged into one, and a single function may have multiple addresses
+/// across codegen units. Two animations built from the same functions usually, but not
+/// always, compare equal. The [`name`](Animation::name) is ignored.
+///
+/// # Default
+///
+/// The default animation is [`Animation::EMPTY`], allowing `#[derive(Default)]` on
+/// types holding an `Animation`.
+#[derive(Debug, Clone, Copy)]
 pub struct Animation {
     /// The segment animating the prior value **out**.
     pub out_seg: AnimationSegment,
     /// The segment animating the new value **in**.
     pub in_seg: AnimationSegment,
+    /// The duration (in seconds) that the *out* and *in* segments overlap.
+    pub(crate) overlap: f32,
+    /// The duration (in seconds) held between the *out* and *in* segments.
+    pub(crate) hold_dur: f32,
+    /// A human-readable name for debugging, such as in the [`inspector`](crate::inspector).
+    /// Empty by default. See [`Animation::with_name`].
+    pub(crate) name: &'static str,
+}
+
+impl Default for Animation {
+    fn default() -> Self {
+        Animation::EMPTY
+    }
+}
+
+/// Create an `Animation` from a total duration and *out*/*in* functions. See
+/// [`Animation::new`].
+///
+/// Function items must be cast to `fn` pointers, as tuples are not coerced.
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// fn fade_in(ui: &mut egui::Ui, normal: f32) {
+///     ui.set_opacity(normal);
+/// }
+/// const FADE_IN: fn(&mut egui::Ui, f32) = fade_in;
+///
+/// let animation: Animation = (0.3, FADE_IN, FADE_IN).into();
+/// assert_eq!(animation, Animation::new(0.3, fade_in, fade_in));
+/// ```
+impl From<(f32, fn(&mut egui::Ui, f32), fn(&mut egui::Ui, f32))> for Animation {
+    fn from(
+        (duration, out_fn, in_fn): (f32, fn(&mut egui::Ui, f32), fn(&mut egui::Ui, f32)),
+    ) -> Self {
+        Animation::new(duration, out_fn, in_fn)
+    }
+}
+
+impl PartialEq for Animation {
+    fn eq(&self, other: &Self) -> bool {
+        self.out_seg == other.out_seg
+            && self.in_seg == other.in_seg
+            && self.overlap.to_bits() == other.overlap.to_bits()
+            && self.hold_dur.to_bits() == other.hold_dur.to_bits()
+    }
+}
+
+impl Eq for Animation {}
+
+impl Hash for Animation {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.out_seg.hash(state);
+        self.in_seg.hash(state);
+        self.overlap.to_bits().hash(state);
+        self.hold_dur.to_bits().hash(state);
+    }
 }
 
 impl Animation {
@@ -61,7 +138,72 @@ impl Animation {
         let out_seg = AnimationSegment::new(segment_duration, out_fn);
         let in_seg = AnimationSegment::new(segment_duration, in_fn);
 
-        Self { out_seg, in_seg }
+        Self::from_segments(out_seg, in_seg)
+    }
+
+    /// Create a new `Animation` with the given `total` duration, split between segments
+    /// by the `split` ratio (`0.0` to `1.0`). The *out* segment takes `total * split`
+    /// seconds, and the *in* segment the remainder. A `split` of `0.5` matches
+    /// [`Animation::new`]. The `split` is clamped to `0.0..=1.0`.
+    ///
+    /// Useful for shifting the balance of an animation with a single setting, while
+    /// keeping the total duration.
+    ///
+    /// # Example
+    /// ```
+    /// # use egui_animate::*;
+    /// // A 0.4 second fade, spending 0.1 seconds fading out and 0.3 seconds fading in.
+    /// const FADE: Animation = Animation::from_total(
+    ///     0.4,
+    ///     0.25,
+    ///     |ui, normal| ui.set_opacity(1.0 - normal),
+    ///     |ui, normal| ui.set_opacity(normal),
+    /// );
+    /// ```
+    pub const fn from_total(
+        total: f32,
+        split: f32,
+        out_fn: fn(&mut egui::Ui, f32),
+        in_fn: fn(&mut egui::Ui, f32),
+    ) -> Self {
+        let split = split.clamp(0.0, 1.0);
+
+        let out_seg = AnimationSegment::new(total * split, out_fn);
+        let in_seg = AnimationSegment::new(total * (1.0 - split), in_fn);
+
+        Self::from_segments(out_seg, in_seg)
+    }
+
+    /// Create a new `Animation` with the given total validated `duration`, split over
+    /// segments. Accepts a [`Secs`] or a [`std::time::Duration`]. See [`Animation::new`].
+    ///
+    /// Unlike [`Animation::new`], this is not a `const fn`, and plain `f32` seconds must
+    /// first be validated with `Secs::try_from` or clamped with [`Secs::saturating`].
+    ///
+    /// # Example
+    /// ```
+    /// # use egui_animate::*;
+    /// # fn main() -> Result<(), InvalidSecs> {
+    /// let anim = Animation::from_secs(
+    ///     Secs::try_from(0.3)?,
+    ///     |ui, normal| ui.set_opacity(1.0 - normal),
+    ///     |ui, normal| ui.set_opacity(normal),
+    /// );
+    ///
+    /// let anim = Animation::from_secs(
+    ///     std::time::Duration::from_millis(300),
+    ///     |ui, normal| ui.set_opacity(1.0 - normal),
+    ///     |ui, normal| ui.set_opacity(normal),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_secs(
+        duration: impl Into<Secs>,
+        out_fn: fn(&mut egui::Ui, f32),
+        in_fn: fn(&mut egui::Ui, f32),
+    ) -> Self {
+        Self::new(duration.into().get(), out_fn, in_fn)
     }
 
     /// Create a new `Animation` with only the *out* segment. Passes the the prior
@@ -70,7 +212,7 @@ impl Animation {
         let out_seg = AnimationSegment::new(duration, out_fn);
         let in_seg = AnimationSegment::EMPTY;
 
-        Self { out_seg, in_seg }
+        Self::from_segments(out_seg, in_seg)
     }
 
     /// Create a new `Animation` with only the *in* segment. Passes the the mutated
@@ -79,17 +221,179 @@ impl Animation {
         let out_seg = AnimationSegment::EMPTY;
         let in_seg = AnimationSegment::new(duration, out_fn);
 
-        Self { out_seg, in_seg }
+        Self::from_segments(out_seg, in_seg)
     }
 
     /// Create a new `Animation` from the given [`AnimationSegment`]s.
     pub const fn from_segments(out_seg: AnimationSegment, in_seg: AnimationSegment) -> Self {
-        Self { out_seg, in_seg }
+        Self {
+            out_seg,
+            in_seg,
+            overlap: 0.0,
+            hold_dur: 0.0,
+            name: "",
+        }
+    }
+
+    /// Create a new cross-fading `Animation`, fading the prior value out while fading
+    /// the new value in over the given `duration`. Both values are rendered for the
+    /// entire animation with [`animate_crossfade`](crate::animate_crossfade).
+    pub const fn crossfade(duration: f32) -> Self {
+        Animation::new(
+            duration * 2.0,
+            |ui, normal| ui.set_opacity(1.0 - normal),
+            |ui, normal| ui.set_opacity(normal),
+        )
+        .with_overlap(duration)
+    }
+
+    /// Linearly interpolate between animations `a` and `b` by `t` (`0.0` to `1.0`), such
+    /// as blending "subtle" and "dramatic" presets with an intensity slider.
+    ///
+    /// Only the segment durations, overlap and hold are interpolated. Functions cannot be
+    /// blended, so the animation functions, keyframes, flags and name are taken from `a`
+    /// when `t < 0.5`, and from `b` otherwise.
+    pub fn lerp(a: &Animation, b: &Animation, t: f32) -> Animation {
+        let lerp = |a: f32, b: f32| egui::lerp(a..=b, t);
+        let nearest = match t < 0.5 {
+            true => a,
+            false => b,
+        };
+
+        Animation {
+            out_seg: AnimationSegment {
+                duration: lerp(a.out_seg.duration, b.out_seg.duration),
+                ..nearest.out_seg
+            },
+            in_seg: AnimationSegment {
+                duration: lerp(a.in_seg.duration, b.in_seg.duration),
+                ..nearest.in_seg
+            },
+            overlap: lerp(a.overlap, b.overlap),
+            hold_dur: lerp(a.hold_dur, b.hold_dur),
+            name: nearest.name,
+        }
+    }
+
+    /// Set the duration (in seconds) that the *out* and *in* segments overlap. For the
+    /// duration of the overlap, the prior and new values are rendered simultaneously on
+    /// separate layers, with the new value drawn above the prior value.
+    ///
+    /// The overlap is limited to the shorter of the two segment durations. The
+    /// [`RunState`](crate::RunState) reports the *out* segment while overlapping.
+    ///
+    /// The overlap is only rendered by [`animate_crossfade`](crate::animate_crossfade),
+    /// which adds the contents once for each value. Other entry points, such as
+    /// [`animate`](crate::animate), play the segments in sequence.
+    pub const fn with_overlap(mut self, overlap: f32) -> Self {
+        self.overlap = overlap;
+        self
+    }
+
+    /// Set the duration (in seconds) held between the *out* and *in* segments, such as
+    /// holding a blank screen between fading screens out and in. The
+    /// [`RunState`](crate::RunState) reports `Hold` for the duration, while the prior value
+    /// remains in the fully *out* state.
+    ///
+    /// The *out* and *in* segments do not overlap while holding.
+    pub const fn with_hold(mut self, hold_dur: f32) -> Self {
+        self.hold_dur = hold_dur;
+        self
+    }
+
+    /// Set whether both segments are rendered on a separate animation layer. See
+    /// [`AnimationSegment::with_layer`].
+    pub const fn with_layer(mut self, uses_layer: bool) -> Self {
+        self.out_seg = self.out_seg.with_layer(uses_layer);
+        self.in_seg = self.in_seg.with_layer(uses_layer);
+        self
+    }
+
+    /// Set whether the *normal* of both segments is eased with the default easing of the
+    /// context. See [`AnimationSegment::with_default_easing`].
+    pub const fn with_default_easing(mut self, default_easing: bool) -> Self {
+        self.out_seg = self.out_seg.with_default_easing(default_easing);
+        self.in_seg = self.in_seg.with_default_easing(default_easing);
+        self
+    }
+
+    /// Set whether the *normal* of both segments is clamped to `0.0..=1.0`. See
+    /// [`AnimationSegment::with_clamp_normal`].
+    pub const fn with_clamp_normal(mut self, clamp_normal: bool) -> Self {
+        self.out_seg = self.out_seg.with_clamp_normal(clamp_normal);
+        self.in_seg = self.in_seg.with_clamp_normal(clamp_normal);
+        self
+    }
+
+    /// Set the human-readable name of the animation, for display in debug tooling. Does
+    /// not affect equality.
+    ///
+    /// # Example
+    /// ```
+    /// # use egui_animate::*;
+    /// let fade = Animation::crossfade(0.3);
+    ///
+    /// assert_eq!(fade.with_name("Fade").name(), "Fade");
+    /// assert_eq!(fade.with_name("Fade"), fade);
+    /// ```
+    pub const fn with_name(mut self, name: &'static str) -> Self {
+        self.name = name;
+        self
+    }
+
+    /// Set the animation function of the *out* segment, keeping its duration,
+    /// keyframes and flags.
+    ///
+    /// # Example
+    /// ```
+    /// # use egui_animate::*;
+    /// // Fade in, as the crossfade, while cutting the prior value out.
+    /// const CUT_FADE: Animation =
+    ///     Animation::crossfade(0.3).with_out_fn(|ui, _| ui.set_invisible());
+    /// ```
+    pub const fn with_out_fn(mut self, out_fn: fn(&mut egui::Ui, f32)) -> Self {
+        self.out_seg.anim_fn = out_fn;
+        self
+    }
+
+    /// Set the animation function of the *in* segment, keeping its duration, keyframes
+    /// and flags. See [`Animation::with_out_fn`].
+    pub const fn with_in_fn(mut self, in_fn: fn(&mut egui::Ui, f32)) -> Self {
+        self.in_seg.anim_fn = in_fn;
+        self
+    }
+
+    /// Get the human-readable name of the animation. Empty if not set. See
+    /// [`Animation::with_name`].
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Get the effective overlap of the *out* and *in* segments. Returns `0.0` while
+    /// holding between segments.
+    pub const fn overlap(&self) -> f32 {
+        match self.hold_dur() > 0.0 {
+            true => 0.0,
+            false => {
+                let max_overlap = self.out_seg.duration.min(self.in_seg.duration).max(0.0);
+                self.overlap.max(0.0).min(max_overlap)
+            }
+        }
+    }
+
+    /// Get the effective duration held between the *out* and *in* segments.
+    pub const fn hold_dur(&self) -> f32 {
+        self.hold_dur.max(0.0)
     }
 
     /// Get the total duration of the animation.
     pub const fn duration(&self) -> f32 {
-        self.out_seg.duration + self.in_seg.duration
+        self.out_seg.duration + self.hold_dur() + self.in_seg.duration - self.overlap()
+    }
+
+    /// Returns `true` if the animation has no duration, such as [`Animation::EMPTY`].
+    pub const fn is_empty(&self) -> bool {
+        self.duration() <= 0.0
     }
 }
 
@@ -108,12 +412,46 @@ impl Animation {
 ///
 /// const ANIM: Animation = Animation::from_segments(FADE_OUT, FADE_IN);
 /// ```
-#[derive(Clone, Copy)]
+///
+/// See [`Animation`] for equality caveats.
+///
+/// # Keyframes
+///
+/// Segments may define `(normal, value)` keyframes with [`AnimationSegment::keyframes`].
+/// The *normal* is mapped to a value linearly interpolated between the bracketing
+/// keyframes, which is passed to the animation function in place of the *normal*.
+///
+/// ```
+/// # use egui_animate::AnimationSegment;
+/// // Slide right, pause, then slide back over 1.0 seconds.
+/// const KEYFRAMES: &[(f32, f32)] = &[(0.0, 0.0), (0.4, 20.0), (0.6, 20.0), (1.0, 0.0)];
+///
+/// const SLIDE: AnimationSegment = AnimationSegment::keyframes(1.0, KEYFRAMES, |ui, x| {
+///     let transform = egui::emath::TSTransform::from_translation(egui::vec2(x, 0.0));
+///     ui.ctx().set_transform_layer(ui.layer_id(), transform);
+/// });
+/// ```
+#[derive(Debug, Clone, Copy)]
 pub struct AnimationSegment {
     /// The duration of the animation, in seconds.
     pub duration: f32,
     /// The [`Ui`] mutating function for the given `f32` normal.
     pub anim_fn: fn(&mut egui::Ui, f32),
+    /// The `(normal, value)` keyframes, ordered by *normal*. Ignored if empty.
+    pub(crate) keyframes: &'static [(f32, f32)],
+    /// Whether the segment is rendered on a separate animation layer. See
+    /// [`AnimationSegment::with_layer`].
+    pub(crate) uses_layer: bool,
+    /// Whether the *normal* is clamped to `0.0..=1.0`. See
+    /// [`AnimationSegment::with_clamp_normal`].
+    pub(crate) clamp_normal: bool,
+    /// The function remapping the *normal*. See [`AnimationSegment::map_normal`].
+    pub(crate) map_fn: Option<fn(f32) -> f32>,
+    /// Whether the *normal* is eased with the default easing of the context. See
+    /// [`AnimationSegment::with_default_easing`].
+    pub(crate) default_easing: bool,
+    /// The function layered after the animation function. See [`AnimationSegment::then`].
+    pub(crate) then_fn: Option<fn(&mut egui::Ui, f32)>,
 }
 
 impl Default for AnimationSegment {
@@ -122,35 +460,214 @@ impl Default for AnimationSegment {
     }
 }
 
+/// Create an `AnimationSegment` from a duration and function. See
+/// [`AnimationSegment::new`].
+impl From<(f32, fn(&mut egui::Ui, f32))> for AnimationSegment {
+    fn from((duration, anim_fn): (f32, fn(&mut egui::Ui, f32))) -> Self {
+        AnimationSegment::new(duration, anim_fn)
+    }
+}
+
+impl PartialEq for AnimationSegment {
+    fn eq(&self, other: &Self) -> bool {
+        let keyframe_bits = |(key, value): &(f32, f32)| (key.to_bits(), value.to_bits());
+
+        self.duration.to_bits() == other.duration.to_bits()
+            && std::ptr::fn_addr_eq(self.anim_fn, other.anim_fn)
+            && self
+                .keyframes
+                .iter()
+                .map(keyframe_bits)
+                .eq(other.keyframes.iter().map(keyframe_bits))
+            && self.uses_layer == other.uses_layer
+            && self.clamp_normal == other.clamp_normal
+            && self.default_easing == other.default_easing
+            && match (self.map_fn, other.map_fn) {
+                (Some(map_fn), Some(other_map_fn)) => std::ptr::fn_addr_eq(map_fn, other_map_fn),
+                (map_fn, other_map_fn) => map_fn.is_none() && other_map_fn.is_none(),
+            }
+            && match (self.then_fn, other.then_fn) {
+                (Some(then_fn), Some(other_then_fn)) => {
+                    std::ptr::fn_addr_eq(then_fn, other_then_fn)
+                }
+                (then_fn, other_then_fn) => then_fn.is_none() && other_then_fn.is_none(),
+            }
+    }
+}
+
+impl Eq for AnimationSegment {}
+
+impl Hash for AnimationSegment {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.duration.to_bits().hash(state);
+        (self.anim_fn as usize).hash(state);
+        self.keyframes.len().hash(state);
+        for (key, value) in self.keyframes {
+            (key.to_bits(), value.to_bits()).hash(state);
+        }
+        self.uses_layer.hash(state);
+        self.clamp_normal.hash(state);
+        self.default_easing.hash(state);
+        self.map_fn.map(|map_fn| map_fn as usize).hash(state);
+        self.then_fn.map(|then_fn| then_fn as usize).hash(state);
+    }
+}
+
 impl AnimationSegment {
     /// An empty placeholder animation segment.
-    const EMPTY: AnimationSegment = AnimationSegment {
-        duration: 0.0,
-        anim_fn: |_, _| {},
-    };
+    pub const EMPTY: AnimationSegment = AnimationSegment::new(0.0, |_, _| {});
 
     /// Create a new `AnimationSegment` from the given `duration` and `animation` function.
     pub const fn new(duration: f32, animation: fn(&mut egui::Ui, f32)) -> Self {
         Self {
             duration,
             anim_fn: animation,
+            keyframes: &[],
+            uses_layer: true,
+            clamp_normal: false,
+            map_fn: None,
+            default_easing: false,
+            then_fn: None,
+        }
+    }
+
+    /// Create a new `AnimationSegment` from the given `duration`, `(normal, value)`
+    /// `keyframes` and `transform` function. The `transform` recieves the value
+    /// interpolated between keyframes for the current *normal*.
+    pub const fn keyframes(
+        duration: f32,
+        keyframes: &'static [(f32, f32)],
+        transform: fn(&mut egui::Ui, f32),
+    ) -> Self {
+        Self {
+            keyframes,
+            ..Self::new(duration, transform)
         }
     }
 
+    /// Set the `(normal, value)` keyframes of the segment, ordered by *normal*. Empty
+    /// keyframes pass the *normal* through unchanged. See [`AnimationSegment::keyframes`].
+    pub const fn with_keyframes(mut self, keyframes: &'static [(f32, f32)]) -> Self {
+        self.keyframes = keyframes;
+        self
+    }
+
+    /// Set whether the segment is rendered on a separate animation layer (`true` by
+    /// default). Segments that only mutate the [`egui::Ui`] (e.g. opacity or clipping)
+    /// may opt out, rendering directly within the parent layer.
+    ///
+    /// Without a layer, the contents are drawn in order with sibling widgets of the
+    /// parent layer, rather than above them. Transforms applied to `ui.layer_id()`
+    /// will transform the **entire** parent layer, and must not be used.
+    pub const fn with_layer(mut self, uses_layer: bool) -> Self {
+        self.uses_layer = uses_layer;
+        self
+    }
+
+    /// Set whether the *normal* is clamped to `0.0..=1.0` before it is passed to the
+    /// animation function (`false` by default). Protects functions that behave poorly
+    /// outside of the range (e.g. clipping) from overshooting normals.
+    pub const fn with_clamp_normal(mut self, clamp_normal: bool) -> Self {
+        self.clamp_normal = clamp_normal;
+        self
+    }
+
+    /// Set whether the *normal* is eased with the default easing of the context (`false`
+    /// by default). See [`easing::set_default_easing`](crate::easing::set_default_easing).
+    ///
+    /// The default easing is read when animating, and takes the place of the remapping
+    /// function, so it is ignored if one is set (see [`AnimationSegment::map_normal`]).
+    /// Functions reversed with [`reverse!`](crate::reverse) mirror the eased *normal*.
+    pub const fn with_default_easing(mut self, default_easing: bool) -> Self {
+        self.default_easing = default_easing;
+        self
+    }
+
+    /// Set a function remapping the *normal* before it is passed to the animation
+    /// function, such as `|n| n * n` or `|n| 1.0 - n`.
+    ///
+    /// The *normal* is remapped first, then clamped (see
+    /// [`AnimationSegment::with_clamp_normal`]), then interpolated between keyframes.
+    pub const fn map_normal(mut self, map_fn: fn(f32) -> f32) -> Self {
+        self.map_fn = Some(map_fn);
+        self
+    }
+
+    /// Layer the given `then_fn` after the animation function, such as tinting after
+    /// sliding, building up a segment without writing a single function.
+    ///
+    /// Both functions are applied in order to the same scoped [`egui::Ui`], and receive
+    /// the same *normal* (or keyframe value). Ui state set by the animation function, such
+    /// as the opacity or clip rect, is visible to `then_fn`, while a layer transform set by
+    /// `then_fn` **replaces** any transform set by the animation function. A single
+    /// function may be layered; use [`combine!`](crate::combine) to layer more.
+    ///
+    /// # Example
+    /// ```
+    /// # use egui_animate::*;
+    /// const SLIDE_IN: AnimationSegment = AnimationSegment::new(0.3, |ui, normal| {
+    ///     presets::slide(ui, egui::vec2((1.0 - normal) * 10.0, 0.0));
+    /// });
+    /// const SLIDE_FADE_IN: AnimationSegment = SLIDE_IN.then(|ui, normal| ui.set_opacity(normal));
+    /// ```
+    pub const fn then(mut self, then_fn: fn(&mut egui::Ui, f32)) -> Self {
+        self.then_fn = Some(then_fn);
+        self
+    }
+
     /// Get the animation duration.
     pub fn duration(&self) -> f32 {
         self.duration
     }
 
+    /// Returns `true` if the segment has no duration, such as [`AnimationSegment::EMPTY`].
+    pub const fn is_empty(&self) -> bool {
+        self.duration <= 0.0
+    }
+
     pub fn duration_mut(&mut self) -> f32 {
         self.duration
     }
 
     /// Get the animation function.
-    pub fn anim_fn(&self) -> fn(&mut egui::Ui, f32) {
+    pub const fn anim_fn(&self) -> fn(&mut egui::Ui, f32) {
         self.anim_fn
     }
 
+    /// Get the function remapping the *normal*, if any. See
+    /// [`AnimationSegment::map_normal`].
+    pub const fn map_fn(&self) -> Option<fn(f32) -> f32> {
+        self.map_fn
+    }
+
+    /// Get the function layered after the animation function, if any. See
+    /// [`AnimationSegment::then`].
+    pub const fn then_fn(&self) -> Option<fn(&mut egui::Ui, f32)> {
+        self.then_fn
+    }
+
+    /// Returns `true` if the segment is rendered on a separate animation layer. See
+    /// [`AnimationSegment::with_layer`].
+    pub const fn uses_layer(&self) -> bool {
+        self.uses_layer
+    }
+
+    /// Returns `true` if the *normal* is clamped to `0.0..=1.0`. See
+    /// [`AnimationSegment::with_clamp_normal`].
+    pub const fn clamp_normal(&self) -> bool {
+        self.clamp_normal
+    }
+
+    /// Returns `true` if the *normal* is eased with the default easing of the context.
+    pub const fn default_easing(&self) -> bool {
+        self.default_easing
+    }
+
+    /// Get the `(normal, value)` keyframes of the segment. Empty if not configured.
+    pub const fn keyframe_table(&self) -> &'static [(f32, f32)] {
+        self.keyframes
+    }
+
     /// Get a mutable reference to the animation function.
     pub fn anim_fn_mut(&mut self) -> &mut fn(&mut egui::Ui, f32) {
         &mut self.anim_fn
@@ -164,21 +681,151 @@ impl AnimationSegment {
         normal: f32,
         add_contents: impl FnOnce(&mut egui::Ui) -> R,
     ) -> R {
-        Self::scope_animation(ui, id, |ui| (self.anim_fn)(ui, normal), add_contents)
+        let layer_id = self.layer_id(ui, id);
+        let anim_fn = |ui: &mut egui::Ui| self.apply_scoped(ui, id, normal);
+        Self::scope_animation(ui, layer_id, anim_fn, add_contents)
+    }
+
+    /// Apply the animation function within a child [`egui::Ui`] placed over the given
+    /// `rect`, without allocating space in the parent. Returns the rect of the contents.
+    ///
+    /// The layer is derived from the given `id`, while the animation function is applied
+    /// as the animation of the given `anim_id`.
+    pub(super) fn animate_overlay<R>(
+        &self,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        anim_id: egui::Id,
+        rect: egui::Rect,
+        normal: f32,
+        add_contents: impl FnOnce(&mut egui::Ui) -> R,
+    ) -> (R, egui::Rect) {
+        let layer_id = self.layer_id(ui, id);
+        if let Some(layer_id) = layer_id {
+            ui.ctx().set_sublayer(ui.layer_id(), layer_id);
+        }
+
+        let mut child_ui = ui.new_child(egui::UiBuilder {
+            layer_id,
+            ..egui::UiBuilder::new()
+                .id_salt(id)
+                .max_rect(rect)
+                .layout(*ui.layout())
+        });
+        self.apply_scoped(&mut child_ui, anim_id, normal);
+        let inner = add_contents(&mut child_ui);
+
+        (inner, child_ui.min_rect())
+    }
+
+    /// Get the value passed to the animation function for the given raw `normal`,
+    /// remapping and clamping the normal and interpolating keyframes if configured.
+    ///
+    /// The default easing of the context is not applied (see
+    /// [`AnimationSegment::with_default_easing`]); use
+    /// [`run_state_eased`](crate::run_state_eased) for the value of the current frame.
+    pub fn eased(&self, normal: f32) -> f32 {
+        self.eased_with(self.map_fn, normal)
+    }
+
+    /// Get the value passed to the animation function for the given raw `normal`, with
+    /// the default easing of the given `ctx` if enabled.
+    pub(crate) fn eased_in(&self, ctx: &egui::Context, normal: f32) -> f32 {
+        let map_fn = match (self.map_fn, self.default_easing) {
+            (None, true) => Some(crate::easing::default_easing(ctx)),
+            (map_fn, _) => map_fn,
+        };
+        self.eased_with(map_fn, normal)
+    }
+
+    /// Get the value for the given raw `normal`, remapped by the given `map_fn`.
+    fn eased_with(&self, map_fn: Option<fn(f32) -> f32>, normal: f32) -> f32 {
+        let normal = map_fn.map_or(normal, |map_fn| map_fn(normal));
+        let normal = match self.clamp_normal {
+            true => normal.clamp(0.0, 1.0),
+            false => normal,
+        };
+
+        match self.keyframes.is_empty() {
+            true => normal,
+            false => Self::interpolate(self.keyframes, normal),
+        }
+    }
+
+    /// Call the animation function for the given `normal`, remapping and clamping the
+    /// normal and interpolating keyframes if configured. See [`AnimationSegment::eased`].
+    ///
+    /// Not part of the public API. Public only for the expansion of
+    /// [`combine!`](crate::combine).
+    #[doc(hidden)]
+    pub fn apply(&self, ui: &mut egui::Ui, normal: f32) {
+        let value = self.eased_in(ui.ctx(), normal);
+        (self.anim_fn)(ui, value);
+        if let Some(then_fn) = self.then_fn {
+            then_fn(ui, value);
+        }
+    }
+
+    /// Call the animation function for the given `normal` as the animation of the given
+    /// `id`, for [`animation_seed`](crate::animation_seed) and
+    /// [`animation_data`](crate::animation_data).
+    fn apply_scoped(&self, ui: &mut egui::Ui, id: egui::Id, normal: f32) {
+        let ctx = ui.ctx().clone();
+        mem::with_animation_id(&ctx, id, || self.apply(ui, normal));
+    }
+
+    /// Linearly interpolate the value of the `keyframes` bracketing the given `normal`.
+    /// Normals outside of the keyframes are clamped to the first and last values.
+    fn interpolate(keyframes: &[(f32, f32)], normal: f32) -> f32 {
+        let next = keyframes.partition_point(|(key, _)| *key <= normal);
+
+        match (
+            next.checked_sub(1).map(|prev| keyframes[prev]),
+            keyframes.get(next),
+        ) {
+            (Some((prev_key, prev_value)), Some(&(next_key, next_value))) => {
+                let t = (normal - prev_key) / (next_key - prev_key);
+                egui::lerp(prev_value..=next_value, t)
+            }
+            (Some((_, value)), None) | (None, Some(&(_, value))) => value,
+            (None, None) => normal,
+        }
+    }
+
+    /// Get the *normal* until which the segment remains unchanged from the given `normal`,
+    /// such as between keyframes of equal value. Returns `None` if the segment may change
+    /// every frame.
+    pub(crate) fn static_until(&self, normal: f32) -> Option<f32> {
+        if self.keyframes.is_empty() || self.map_fn.is_some() || self.default_easing {
+            return None;
+        }
+        let next = self.keyframes.partition_point(|(key, _)| *key <= normal);
+
+        match (
+            next.checked_sub(1).map(|prev| self.keyframes[prev]),
+            self.keyframes.get(next),
+        ) {
+            (Some((_, prev_value)), Some(&(next_key, next_value))) => {
+                (prev_value == next_value).then_some(next_key)
+            }
+            (None, Some(&(next_key, _))) => Some(next_key),
+            (Some(_), None) => Some(1.0),
+            (None, None) => None,
+        }
     }
 
     /// Create a child [`egui::Ui`] for animation.
     fn scope_animation<R>(
         ui: &mut egui::Ui,
-        id: egui::Id,
+        layer_id: Option<egui::LayerId>,
         anim_fn: impl FnOnce(&mut egui::Ui),
         add_contents: impl FnOnce(&mut egui::Ui) -> R,
     ) -> R {
-        let layer_id = Self::animation_layer(ui, id);
         ui.scope_builder(
-            egui::UiBuilder::new()
-                .id_salt("animation_scope")
-                .layer_id(layer_id),
+            egui::UiBuilder {
+                layer_id,
+                ..egui::UiBuilder::new().id_salt("animation_scope")
+            },
             |ui| {
                 anim_fn(ui);
                 add_contents(ui)
@@ -187,8 +834,249 @@ impl AnimationSegment {
         .inner
     }
 
+    /// Get the animation layer id of the segment, or `None` if the segment does not
+    /// use a layer.
+    fn layer_id(&self, ui: &mut egui::Ui, id: egui::Id) -> Option<egui::LayerId> {
+        self.uses_layer.then(|| Self::animation_layer(ui, id))
+    }
+
     /// Get the animation layer id.
     pub(crate) fn animation_layer(ui: &mut egui::Ui, id: egui::Id) -> egui::LayerId {
         egui::LayerId::new(ui.layer_id().order, id)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod animation {
+        use super::*;
+
+        fn out_fn(ui: &mut egui::Ui, normal: f32) {
+            ui.set_opacity(1.0 - normal);
+        }
+        fn in_fn(ui: &mut egui::Ui, normal: f32) {
+            ui.set_opacity(normal);
+        }
+
+        #[test]
+        fn test_eq() {
+            let anim = Animation::new(1.0, out_fn, in_fn);
+
+            assert_eq!(anim, Animation::new(1.0, out_fn, in_fn));
+            assert_ne!(anim, Animation::new(2.0, out_fn, in_fn));
+            assert_ne!(anim, Animation::new(1.0, in_fn, out_fn));
+            assert_ne!(anim, anim.with_overlap(0.5));
+        }
+
+        #[test]
+        fn test_from_tuple() {
+            let out_fn: fn(&mut egui::Ui, f32) = out_fn;
+            let in_fn: fn(&mut egui::Ui, f32) = in_fn;
+
+            assert_eq!(
+                Animation::from((1.0, out_fn, in_fn)),
+                Animation::new(1.0, out_fn, in_fn)
+            );
+            assert_eq!(
+                AnimationSegment::from((1.0, in_fn)),
+                AnimationSegment::new(1.0, in_fn)
+            );
+        }
+
+        #[test]
+        fn test_default() {
+            assert_eq!(Animation::default(), Animation::EMPTY);
+            assert_eq!(Animation::default().duration(), 0.0);
+        }
+
+        #[test]
+        fn test_lerp() {
+            let subtle = Animation::new(0.5, out_fn, in_fn);
+            let dramatic = Animation::new(1.5, in_fn, out_fn).with_overlap(0.5);
+
+            let blend = Animation::lerp(&subtle, &dramatic, 0.25);
+            assert_eq!(blend.out_seg.duration, 0.375);
+            assert_eq!(blend.overlap, 0.125);
+            assert!(std::ptr::fn_addr_eq(
+                blend.out_seg.anim_fn,
+                out_fn as fn(_, _)
+            ));
+            let blend = Animation::lerp(&subtle, &dramatic, 0.5);
+            assert_eq!(blend.in_seg.duration, 0.5);
+            assert!(std::ptr::fn_addr_eq(
+                blend.in_seg.anim_fn,
+                out_fn as fn(_, _)
+            ));
+            assert_eq!(Animation::lerp(&subtle, &dramatic, 1.0), dramatic);
+        }
+
+        #[test]
+        fn test_from_total() {
+            let anim = Animation::from_total(0.4, 0.25, out_fn, in_fn);
+
+            assert_eq!(anim.out_seg.duration, 0.1);
+            assert_eq!(anim.in_seg.duration, 0.3);
+            assert_eq!(anim.duration(), 0.4);
+            assert_eq!(
+                Animation::from_total(1.0, 0.5, out_fn, in_fn),
+                Animation::new(1.0, out_fn, in_fn)
+            );
+            let anim = Animation::from_total(1.0, 1.5, out_fn, in_fn);
+            assert_eq!((anim.out_seg.duration, anim.in_seg.duration), (1.0, 0.0));
+        }
+
+        #[test]
+        fn test_with_fn() {
+            let anim = Animation::new(1.0, out_fn, out_fn).with_overlap(0.25);
+
+            assert_eq!(
+                anim.with_in_fn(in_fn),
+                Animation::new(1.0, out_fn, in_fn).with_overlap(0.25)
+            );
+            assert_eq!(
+                anim.with_out_fn(in_fn),
+                Animation::new(1.0, in_fn, out_fn).with_overlap(0.25)
+            );
+        }
+
+        #[test]
+        fn test_with_name() {
+            let anim = Animation::new(1.0, out_fn, in_fn);
+
+            assert_eq!(anim.name(), "");
+            assert_eq!(anim.with_name("Fade").name(), "Fade");
+            assert_eq!(anim.with_name("Fade"), anim);
+            assert_eq!(
+                Animation::lerp(&anim.with_name("Fade"), &anim, 0.25).name(),
+                "Fade"
+            );
+        }
+
+        #[test]
+        fn test_invalid_overlap() {
+            let anim = Animation::new(1.0, out_fn, in_fn);
+
+            assert_eq!(anim.with_overlap(1.0).overlap(), 0.5);
+            assert_eq!(anim.with_overlap(f32::NAN).overlap(), 0.0);
+            // Negative and NaN durations do not panic.
+            assert_eq!(Animation::new(-1.0, out_fn, in_fn).overlap(), 0.0);
+            assert_eq!(Animation::new(f32::NAN, out_fn, in_fn).overlap(), 0.0);
+        }
+
+        #[test]
+        fn test_hash_map_key() {
+            let mut map = std::collections::HashMap::new();
+            map.insert(Animation::new(1.0, out_fn, in_fn), "fade");
+
+            assert_eq!(map.get(&Animation::new(1.0, out_fn, in_fn)), Some(&"fade"));
+            assert_eq!(map.get(&Animation::EMPTY), None);
+        }
+    }
+
+    mod animation_segment {
+        use super::*;
+
+        const TEST_KEYFRAMES: &[(f32, f32)] = &[(0.0, 0.0), (0.5, 10.0), (0.75, 10.0), (1.0, 0.0)];
+
+        #[test]
+        fn test_interpolate() {
+            let interpolate = |normal| AnimationSegment::interpolate(TEST_KEYFRAMES, normal);
+
+            assert_eq!(interpolate(-1.0), 0.0);
+            assert_eq!(interpolate(0.0), 0.0);
+            assert_eq!(interpolate(0.25), 5.0);
+            assert_eq!(interpolate(0.5), 10.0);
+            assert_eq!(interpolate(0.6), 10.0);
+            assert_eq!(interpolate(0.875), 5.0);
+            assert_eq!(interpolate(1.0), 0.0);
+            assert_eq!(interpolate(2.0), 0.0);
+        }
+
+        #[test]
+        fn test_static_until() {
+            let segment = AnimationSegment::keyframes(1.0, TEST_KEYFRAMES, |_, _| {});
+
+            assert_eq!(segment.static_until(0.25), None);
+            assert_eq!(segment.static_until(0.5), Some(0.75));
+            assert_eq!(segment.static_until(0.6), Some(0.75));
+            assert_eq!(segment.static_until(0.8), None);
+            assert_eq!(AnimationSegment::EMPTY.static_until(0.5), None);
+        }
+
+        #[test]
+        fn test_with_keyframes() {
+            let segment = AnimationSegment::new(1.0, |_, _| {}).with_keyframes(TEST_KEYFRAMES);
+
+            assert_eq!(segment.keyframe_table(), TEST_KEYFRAMES);
+            assert_eq!(segment.eased(0.25), 5.0);
+            assert!(AnimationSegment::EMPTY.keyframe_table().is_empty());
+        }
+
+        #[test]
+        fn test_with_layer() {
+            let segment = AnimationSegment::new(1.0, |_, _| {});
+
+            assert!(segment.uses_layer());
+            assert!(!segment.with_layer(false).uses_layer());
+            assert_ne!(segment, segment.with_layer(false));
+        }
+
+        #[test]
+        fn test_with_clamp_normal() {
+            let segment = AnimationSegment::new(1.0, |_, _| {}).with_clamp_normal(true);
+
+            assert!(segment.clamp_normal());
+            assert!(!AnimationSegment::EMPTY.clamp_normal());
+            assert_eq!(segment.eased(1.5), 1.0);
+        }
+
+        #[test]
+        fn test_map_normal() {
+            fn fade(ui: &mut egui::Ui, normal: f32) {
+                ui.set_opacity(normal);
+            }
+            let segment = AnimationSegment::new(1.0, fade).map_normal(|n| 1.0 - n);
+            let ctx = egui::Context::default();
+
+            let _ = ctx.run(Default::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    segment.apply(ui, 0.25);
+                    assert_eq!(ui.opacity(), 0.75);
+                });
+            });
+            assert_ne!(segment, AnimationSegment::new(1.0, fade));
+            assert!(segment.map_fn().is_some());
+            assert!(AnimationSegment::EMPTY.map_fn().is_none());
+        }
+
+        #[test]
+        fn test_then() {
+            fn fade(ui: &mut egui::Ui, normal: f32) {
+                ui.set_opacity(normal);
+            }
+            fn halve_opacity(ui: &mut egui::Ui, _: f32) {
+                ui.multiply_opacity(0.5);
+            }
+            let segment = AnimationSegment::new(1.0, fade).then(halve_opacity);
+            let ctx = egui::Context::default();
+
+            let _ = ctx.run(Default::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    segment.apply(ui, 0.5);
+                    assert_eq!(ui.opacity(), 0.25);
+                });
+            });
+            assert!(std::ptr::fn_addr_eq(segment.anim_fn(), fade as fn(_, _)));
+            assert!(segment.then_fn().is_some());
+            assert_ne!(segment, AnimationSegment::new(1.0, fade));
+        }
+
+        #[test]
+        fn test_interpolate_single_keyframe() {
+            assert_eq!(AnimationSegment::interpolate(&[(0.5, 3.0)], 0.0), 3.0);
+            assert_eq!(AnimationSegment::interpolate(&[(0.5, 3.0)], 1.0), 3.0);
+        }
+    }
+}
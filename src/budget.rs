@@ -0,0 +1,36 @@
+//! Frame budget handling of animations under load.
+use crate::mem;
+
+/// Set the frame budget (in seconds) for all animations of the given [`egui::Context`].
+/// Running animations finish immediately on frames where [`egui::InputState::stable_dt`]
+/// exceeds the budget. Disabled with `None` (the default).
+///
+/// Going over budget snaps running animations to their end, for both the rendered
+/// animation and the queried state, such as [`run_state`](crate::run_state) and
+/// [`next_repaint_after`](crate::next_repaint_after).
+///
+/// By default, animations follow the elapsed time, skipping ahead by the length of each
+/// frame. Under heavy load, the remainder of an animation is shown in few, uneven steps.
+/// A frame budget prioritizes responsiveness instead, skipping the choppy remainder.
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// # let ctx = egui::Context::default();
+/// // Finish animations on frames slower than 10 fps.
+/// set_frame_budget(&ctx, Some(0.1));
+/// ```
+pub fn set_frame_budget(ctx: &egui::Context, budget: Option<f32>) {
+    mem::set_frame_budget(ctx, budget);
+}
+
+/// Get the frame budget (in seconds) for all animations of the given [`egui::Context`].
+/// See [`set_frame_budget`].
+pub fn frame_budget(ctx: &egui::Context) -> Option<f32> {
+    mem::get_frame_budget(ctx)
+}
+
+/// Returns `true` if the current frame exceeds the frame budget.
+pub(crate) fn over_frame_budget(ctx: &egui::Context) -> bool {
+    frame_budget(ctx).is_some_and(|budget| ctx.input(|input| input.stable_dt) > budget)
+}
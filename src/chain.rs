@@ -0,0 +1,107 @@
+//! Orchestration of animations across ids.
+use crate::mem;
+
+/// Call `then` once when the animation of the given `trigger_id` completes. Use to stage
+/// a sequence of animations across components, changing the value of another animation
+/// once the first completes.
+///
+/// Completion is detected when the animation stops running between passes, so `after`
+/// should be called every pass, after the [`animate`](crate::animate) call of the
+/// `trigger_id`. Animations settled without completing, such as those of removed
+/// widgets, also trigger `then`.
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # const FADE: Animation = Animation::EMPTY;
+/// # let (mut show_title, mut show_body) = (true, false);
+/// # let ctx = egui::Context::default();
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// animate(ui, "title", show_title, FADE, |ui, show| {
+///     if show {
+///         ui.heading("Title");
+///     }
+/// });
+/// // Show the body once the title has faded in.
+/// after(ui, "title", || show_body = true);
+///
+/// animate(ui, "body", show_body, FADE, |ui, show| {
+///     if show {
+///         ui.label("Body");
+///     }
+/// });
+/// # });
+/// # });
+/// ```
+pub fn after(ui: &mut egui::Ui, trigger_id: impl Into<egui::Id>, then: impl FnOnce()) {
+    let trigger_id = trigger_id.into();
+    let running = mem::get_start_time(ui, trigger_id).is_some();
+
+    if mem::observe_running(ui, trigger_id, running) && !running {
+        then();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Animation, animate};
+
+    const TEST_ANIM: Animation = Animation::new(1.0, |_, _| {}, |_, _| {});
+
+    /// Run a single frame at the given `time`, returning the number of calls to `then`.
+    fn run_frame(ctx: &egui::Context, time: f64, value: i32) -> usize {
+        let mut calls = 0;
+        let input = egui::RawInput {
+            time: Some(time),
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                animate(ui, "test_anim", value, TEST_ANIM, |_, _| {});
+                // Multiple observers of a pass see the same completion.
+                after(ui, "test_anim", || calls += 1);
+                after(ui, "test_anim", || calls += 1);
+            });
+        });
+        calls
+    }
+
+    #[test]
+    fn test_after() {
+        let ctx = egui::Context::default();
+
+        assert_eq!(run_frame(&ctx, 0.0, 0), 0);
+        assert_eq!(run_frame(&ctx, 1.0, 1), 0);
+        assert_eq!(run_frame(&ctx, 1.5, 1), 0);
+        assert_eq!(run_frame(&ctx, 2.0, 1), 2);
+        assert_eq!(run_frame(&ctx, 2.5, 1), 0);
+    }
+
+    #[test]
+    fn test_after_settled() {
+        let ctx = egui::Context::default();
+
+        run_frame(&ctx, 0.0, 0);
+        run_frame(&ctx, 1.0, 1);
+
+        // Skip the animation for a frame, settling it.
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let mut calls = 0;
+                after(ui, "test_anim", || calls += 1);
+                assert_eq!(calls, 0);
+            });
+        });
+        let mut calls = 0;
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                after(ui, "test_anim", || calls += 1);
+            });
+        });
+        assert_eq!(calls, 1);
+    }
+}
@@ -0,0 +1,399 @@
+//! Animation timing, independent of `egui::Ui`.
+use crate::{Animation, RunState};
+
+/// The timing of an *out*/*in* animation, from the animation start time and current time.
+///
+/// Computes the elapsed time and *normal* of each segment with plain arithmetic, without
+/// requiring an [`egui::Context`]. Useful for driving custom rendering with timing
+/// consistent with [`animate`](crate::animate).
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// // An animation started at 1.0 seconds, with 0.5 second segments.
+/// let clock = AnimationClock::new(1.0, 1.75, 0.5, 0.5);
+///
+/// assert_eq!(clock.out_elapsed_normal(), None);
+/// assert_eq!(clock.in_elapsed_normal(), Some(0.5));
+/// assert_eq!(clock.run_state(), RunState::InSeg(0.5));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct AnimationClock {
+    /// The time (in seconds) the animation started.
+    pub start_time: f64,
+    /// The current time (in seconds).
+    pub current_time: f64,
+    /// The *out* segment duration.
+    pub out_dur: f32,
+    /// The *in* segment duration.
+    pub in_dur: f32,
+    /// The duration that the *out* and *in* segments overlap.
+    pub overlap: f32,
+    /// The duration held between the *out* and *in* segments.
+    pub hold_dur: f32,
+}
+
+impl AnimationClock {
+    /// Create a new `AnimationClock` from the `start_time`, `current_time` and segment
+    /// durations. A `start_time` later than the `current_time` is treated as freshly started.
+    pub const fn new(start_time: f64, current_time: f64, out_dur: f32, in_dur: f32) -> Self {
+        Self {
+            start_time: start_time.min(current_time),
+            current_time,
+            out_dur,
+            in_dur,
+            overlap: 0.0,
+            hold_dur: 0.0,
+        }
+    }
+
+    /// Create a new `AnimationClock` from the `start_time`, `current_time` and the segment
+    /// durations, overlap and hold of the given `Animation`.
+    pub const fn from_animation(start_time: f64, current_time: f64, animation: &Animation) -> Self {
+        Self::new(
+            start_time,
+            current_time,
+            animation.out_seg.duration,
+            animation.in_seg.duration,
+        )
+        .with_overlap(animation.overlap())
+        .with_hold(animation.hold_dur())
+    }
+
+    /// Set the duration that the *out* and *in* segments overlap. See
+    /// [`Animation::with_overlap`].
+    pub const fn with_overlap(mut self, overlap: f32) -> Self {
+        self.overlap = overlap;
+        self
+    }
+
+    /// Set the duration held between the *out* and *in* segments. See
+    /// [`Animation::with_hold`].
+    pub const fn with_hold(mut self, hold_dur: f32) -> Self {
+        self.hold_dur = hold_dur;
+        self
+    }
+
+    /// Get the effective overlap of the *out* and *in* segments. Returns `0.0` while
+    /// holding between segments.
+    pub const fn overlap(&self) -> f32 {
+        match self.hold_dur() > 0.0 {
+            true => 0.0,
+            false => self
+                .overlap
+                .max(0.0)
+                .min(self.out_dur.min(self.in_dur).max(0.0)),
+        }
+    }
+
+    /// Get the effective duration held between the *out* and *in* segments.
+    pub const fn hold_dur(&self) -> f32 {
+        self.hold_dur.max(0.0)
+    }
+
+    /// Get the **out** segment start time.
+    #[inline]
+    pub fn out_start(&self) -> f64 {
+        self.start_time
+    }
+
+    /// Get the **out** segment end time.
+    #[inline]
+    pub fn out_end(&self) -> f64 {
+        self.out_start() + self.out_dur as f64
+    }
+
+    /// Get the elapsed time of the **out** segment. Returns `Some(0.0)` if the animation
+    /// has yet to begin, and `None` if the animation has finished.
+    ///
+    /// The elapsed time is never negative. A `current_time` earlier than the
+    /// `start_time`, such as from a clock going backward, is treated as the start of the
+    /// animation.
+    pub fn out_elapsed(&self) -> Option<f64> {
+        let out_elapsed = (self.current_time - self.out_start()).max(0.0);
+        (out_elapsed < self.out_dur as f64).then_some(out_elapsed)
+    }
+
+    /// Get the elapsed normal of the **out** segment. Returns `Some(0.0)` if the animation
+    /// has yet to begin, and `None` if the animation has finished.
+    pub fn out_elapsed_normal(&self) -> Option<f32> {
+        self.out_elapsed()
+            .map(|elapsed| (elapsed / self.out_dur as f64) as f32)
+    }
+
+    /// Get the elapsed time of the hold between segments. Returns `None` outside of the
+    /// hold.
+    pub fn hold_elapsed(&self) -> Option<f64> {
+        let hold_elapsed = self.current_time - self.out_end();
+        (hold_elapsed >= 0.0 && hold_elapsed < self.hold_dur() as f64).then_some(hold_elapsed)
+    }
+
+    /// Get the elapsed normal of the hold between segments. Returns `None` outside of the
+    /// hold.
+    pub fn hold_elapsed_normal(&self) -> Option<f32> {
+        self.hold_elapsed()
+            .map(|elapsed| (elapsed / self.hold_dur() as f64) as f32)
+    }
+
+    /// Get the **in** segment start time.
+    #[inline]
+    pub fn in_start(&self) -> f64 {
+        self.out_end() + self.hold_dur() as f64 - self.overlap() as f64
+    }
+
+    /// Get the **in** segment end time.
+    #[inline]
+    pub fn in_end(&self) -> f64 {
+        self.in_start() + self.in_dur as f64
+    }
+
+    /// Get the elapsed time of the **in** segment. Returns `Some(0.0)` if the animation
+    /// has yet to begin, and `None` if the animation has finished.
+    pub fn in_elapsed(&self) -> Option<f64> {
+        let in_elapsed = (self.current_time - self.in_start()).max(0.0);
+        (in_elapsed < self.in_dur as f64).then_some(in_elapsed)
+    }
+
+    /// Get the elapsed normal of the **in** segment. Returns `Some(0.0)` if the animation
+    /// has yet to begin, and `None` if the animation has finished.
+    pub fn in_elapsed_normal(&self) -> Option<f32> {
+        self.in_elapsed()
+            .map(|elapsed| (elapsed / self.in_dur as f64) as f32)
+    }
+
+    /// Get the elapsed normal of the **in** segment while overlapping the **out**
+    /// segment. Returns `None` outside of the overlap.
+    pub fn overlap_in_normal(&self) -> Option<f32> {
+        let overlapping =
+            self.current_time >= self.in_start() && self.current_time < self.out_end();
+        overlapping.then(|| self.in_elapsed_normal()).flatten()
+    }
+
+    /// Get the clock playing this animation backward from the current time, swapping the
+    /// *out* and *in* segment durations.
+    ///
+    /// The reversed clock mirrors the current position: the *normal* `n` of the *out*
+    /// segment becomes the *normal* `1.0 - n` of the *in* segment, and vice versa.
+    pub fn reversed(&self) -> Self {
+        let remaining = (self.in_end() - self.current_time).max(0.0);
+        Self {
+            start_time: self.current_time - remaining,
+            out_dur: self.in_dur,
+            in_dur: self.out_dur,
+            ..*self
+        }
+    }
+
+    /// Get the `RunState` for the current time.
+    pub fn run_state(&self) -> RunState {
+        if let Some(normal) = self.out_elapsed_normal() {
+            RunState::OutSeg(normal)
+        } else if let Some(normal) = self.hold_elapsed_normal() {
+            RunState::Hold(normal)
+        } else if let Some(normal) = self.in_elapsed_normal() {
+            RunState::InSeg(normal)
+        } else {
+            RunState::None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_CLOCK: AnimationClock = AnimationClock::new(1.0, 1.0, 1.5, 1.5);
+
+    #[test]
+    fn test_out_end() {
+        let clock = TEST_CLOCK;
+        assert_eq!(clock.out_end(), 2.5);
+    }
+
+    #[test]
+    fn test_start_time_after_current_time() {
+        let mut clock = AnimationClock::new(10.0, 1.0, 1.5, 1.5);
+
+        assert_eq!(clock.start_time, 1.0);
+        assert_eq!(clock.out_elapsed(), Some(0.0));
+        assert_eq!(clock.run_state(), RunState::OutSeg(0.0));
+        clock.current_time = 1.75;
+        assert_eq!(clock.out_elapsed_normal(), Some(0.5));
+    }
+
+    #[test]
+    fn test_current_time_before_start_time() {
+        let mut clock = TEST_CLOCK;
+        clock.current_time = 0.5;
+
+        // Reports the start of the animation, rendering the start value.
+        assert_eq!(clock.out_elapsed(), Some(0.0));
+        assert_eq!(clock.hold_elapsed(), None);
+        assert_eq!(clock.overlap_in_normal(), None);
+        assert_eq!(clock.run_state(), RunState::OutSeg(0.0));
+        let clock = clock.with_hold(1.0).with_overlap(0.5);
+        assert_eq!(clock.run_state(), RunState::OutSeg(0.0));
+
+        // Without an out segment, the in segment has yet to begin.
+        let mut clock = AnimationClock::new(1.0, 1.0, 0.0, 1.5);
+        clock.current_time = 0.5;
+        assert_eq!(clock.out_elapsed(), None);
+        assert_eq!(clock.run_state(), RunState::InSeg(0.0));
+    }
+
+    #[test]
+    fn test_elapsed_precision() {
+        let mut clock = TEST_CLOCK;
+        clock.start_time = 1_000_000.0;
+        clock.current_time = 1_000_000.000_1;
+
+        assert!((clock.out_elapsed().unwrap() - 0.000_1).abs() < 1e-9);
+        clock.current_time = 1_000_001.75;
+        assert_eq!(clock.out_elapsed(), None);
+        assert_eq!(clock.in_elapsed(), Some(0.25));
+        assert_eq!(clock.in_elapsed_normal(), Some(0.25 / 1.5));
+    }
+
+    #[test]
+    fn test_out_elapsed() {
+        let mut clock = TEST_CLOCK;
+
+        assert_eq!(clock.out_elapsed(), Some(0.0));
+        clock.current_time = 2.0;
+        assert_eq!(clock.out_elapsed(), Some(1.0));
+        clock.current_time = 3.0;
+        assert_eq!(clock.out_elapsed(), None);
+        clock.current_time = 4.0;
+        assert_eq!(clock.out_elapsed(), None);
+    }
+    #[test]
+    fn test_out_elapsed_normal() {
+        let mut clock = TEST_CLOCK;
+
+        assert_eq!(clock.out_elapsed_normal(), Some(0.0));
+        clock.current_time = 1.75;
+        assert_eq!(clock.out_elapsed_normal(), Some(0.5));
+        clock.current_time = 3.0;
+        assert_eq!(clock.out_elapsed_normal(), None);
+        clock.current_time = 4.0;
+        assert_eq!(clock.out_elapsed_normal(), None);
+    }
+
+    #[test]
+    fn test_in_end() {
+        let clock = TEST_CLOCK;
+        assert_eq!(clock.in_end(), 4.0);
+    }
+
+    #[test]
+    fn test_in_elapsed() {
+        let mut clock = TEST_CLOCK;
+
+        assert_eq!(clock.in_elapsed(), Some(0.0));
+        clock.current_time = 2.0;
+        assert_eq!(clock.in_elapsed(), Some(0.0));
+        clock.current_time = 3.0;
+        assert_eq!(clock.in_elapsed(), Some(0.5));
+        clock.current_time = 4.0;
+        assert_eq!(clock.in_elapsed(), None);
+        clock.current_time = 5.0;
+        assert_eq!(clock.in_elapsed(), None);
+    }
+    #[test]
+    fn test_in_elapsed_normal() {
+        let mut clock = TEST_CLOCK;
+
+        assert_eq!(clock.in_elapsed_normal(), Some(0.0));
+        clock.current_time = 2.0;
+        assert_eq!(clock.in_elapsed_normal(), Some(0.0));
+        clock.current_time = 3.25;
+        assert_eq!(clock.in_elapsed_normal(), Some(0.5));
+        clock.current_time = 4.0;
+        assert_eq!(clock.in_elapsed_normal(), None);
+        clock.current_time = 5.0;
+        assert_eq!(clock.in_elapsed_normal(), None);
+    }
+
+    #[test]
+    fn test_overlap_in_normal() {
+        let mut clock = TEST_CLOCK.with_overlap(0.5);
+
+        assert_eq!(clock.in_start(), 2.0);
+        assert_eq!(clock.in_end(), 3.5);
+        assert_eq!(clock.overlap_in_normal(), None);
+        clock.current_time = 2.0;
+        assert_eq!(clock.overlap_in_normal(), Some(0.0));
+        clock.current_time = 2.375;
+        assert_eq!(clock.overlap_in_normal(), Some(0.25));
+        clock.current_time = 2.5;
+        assert_eq!(clock.overlap_in_normal(), None);
+        assert_eq!(clock.run_state(), RunState::InSeg(1.0 / 3.0));
+    }
+
+    #[test]
+    fn test_zero_out_dur() {
+        let mut clock = AnimationClock::new(1.0, 1.0, 0.0, 1.5);
+
+        assert_eq!(clock.out_elapsed_normal(), None);
+        assert_eq!(clock.run_state(), RunState::InSeg(0.0));
+        clock.current_time = 1.75;
+        assert_eq!(clock.run_state(), RunState::InSeg(0.5));
+        clock.current_time = 2.5;
+        assert_eq!(clock.run_state(), RunState::None);
+    }
+
+    #[test]
+    fn test_zero_in_dur() {
+        let mut clock = AnimationClock::new(1.0, 1.0, 1.5, 0.0).with_overlap(0.5);
+
+        assert_eq!(clock.overlap(), 0.0);
+        assert_eq!(clock.run_state(), RunState::OutSeg(0.0));
+        clock.current_time = 1.75;
+        assert_eq!(clock.run_state(), RunState::OutSeg(0.5));
+        clock.current_time = 2.5;
+        assert_eq!(clock.in_elapsed_normal(), None);
+        assert_eq!(clock.run_state(), RunState::None);
+    }
+
+    #[test]
+    fn test_reversed() {
+        let mut clock = AnimationClock::new(1.0, 1.5, 2.0, 1.0);
+
+        assert_eq!(clock.run_state(), RunState::OutSeg(0.25));
+        assert_eq!(clock.reversed().run_state(), RunState::InSeg(0.75));
+        clock.current_time = 3.75;
+        assert_eq!(clock.run_state(), RunState::InSeg(0.75));
+        assert_eq!(clock.reversed().run_state(), RunState::OutSeg(0.25));
+        assert_eq!(clock.reversed().in_end(), 6.5);
+    }
+
+    #[test]
+    fn test_zero_durations() {
+        let clock = AnimationClock::new(1.0, 1.0, 0.0, 0.0);
+
+        assert_eq!(clock.out_elapsed_normal(), None);
+        assert_eq!(clock.in_elapsed_normal(), None);
+        assert_eq!(clock.run_state(), RunState::None);
+    }
+
+    #[test]
+    fn test_hold_timeline() {
+        let mut clock = TEST_CLOCK.with_hold(1.0).with_overlap(0.5);
+
+        assert_eq!(clock.in_start(), 3.5);
+        assert_eq!(clock.in_end(), 5.0);
+        assert_eq!(clock.hold_elapsed(), None);
+        assert_eq!(clock.run_state(), RunState::OutSeg(0.0));
+        clock.current_time = 2.5;
+        assert_eq!(clock.run_state(), RunState::Hold(0.0));
+        clock.current_time = 3.0;
+        assert_eq!(clock.run_state(), RunState::Hold(0.5));
+        clock.current_time = 3.5;
+        assert_eq!(clock.hold_elapsed(), None);
+        assert_eq!(clock.run_state(), RunState::InSeg(0.0));
+        clock.current_time = 4.25;
+        assert_eq!(clock.run_state(), RunState::InSeg(0.5));
+        clock.current_time = 5.0;
+        assert_eq!(clock.run_state(), RunState::None);
+    }
+}
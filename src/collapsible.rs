@@ -0,0 +1,125 @@
+//! Animated collapsible sections.
+use crate::state::animate_with;
+use crate::{Animation, AnimationResult, presets, reverse};
+
+/// Show a `header`, followed by a collapsible `body` that expands and fades in when
+/// `open` becomes `true`, and collapses and fades out when `open` becomes `false`, over
+/// the given `duration` (in seconds).
+///
+/// Similar to [`egui::CollapsingHeader`], with the state of `open` kept by the caller,
+/// typically toggled within the `header`. The body is rendered through the collapse,
+/// and the surrounding layout reflows as the reserved height changes. See
+/// [`presets::reveal_height`].
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// let mut open = false;
+///
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// collapsible_animated(
+///     ui,
+///     "details",
+///     open,
+///     0.3,
+///     |ui| {
+///         if ui.button("Details").clicked() {
+///             open = !open;
+///         }
+///     },
+///     |ui| {
+///         ui.label("Section contents");
+///     },
+/// );
+/// #
+/// # });
+/// # });
+/// ```
+pub fn collapsible_animated(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    open: bool,
+    duration: f32,
+    header: impl FnOnce(&mut egui::Ui),
+    body: impl FnOnce(&mut egui::Ui),
+) -> AnimationResult {
+    let mut body = Some(body);
+
+    header(ui);
+    animate_with(
+        ui,
+        id.into(),
+        open,
+        |_, open| {
+            match open {
+                true => Animation::new_in(duration, reveal),
+                false => Animation::new_out(duration, reverse!(reveal)),
+            }
+            .with_clamp_normal(true)
+        },
+        |ui, open| {
+            if let Some(body) = open.then(|| body.take()).flatten() {
+                body(ui);
+            }
+        },
+        |_| {},
+    )
+}
+
+/// Reveal the height of the contents, fading the contents in.
+fn reveal(ui: &mut egui::Ui, normal: f32) {
+    presets::reveal_height(ui, normal);
+    ui.multiply_opacity(normal);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collapsible_animated() {
+        let ctx = egui::Context::default();
+        let run_frame = |time: f64, open: bool| {
+            let mut allocated = 0.0;
+            let input = egui::RawInput {
+                time: Some(time),
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let top = ui.cursor().top();
+                    collapsible_animated(
+                        ui,
+                        "collapsible",
+                        open,
+                        1.0,
+                        |_| {},
+                        |ui| {
+                            ui.allocate_space(egui::vec2(10.0, 100.0));
+                        },
+                    );
+                    allocated = ui.cursor().top() - top;
+                });
+            });
+            allocated
+        };
+
+        let spacing = ctx.style().spacing.item_spacing.y;
+
+        assert_eq!(run_frame(0.0, false), 0.0);
+        assert_eq!(run_frame(1.0, true), spacing);
+        assert_eq!(run_frame(1.5, true), 50.0 + spacing);
+        assert_eq!(run_frame(2.0, true), 100.0 + spacing);
+        assert_eq!(run_frame(2.5, true), 100.0 + spacing);
+        // The body is rendered through the collapse.
+        assert_eq!(run_frame(3.0, false), 100.0 + spacing);
+        assert_eq!(run_frame(3.25, false), 75.0 + spacing);
+        assert_eq!(run_frame(4.0, false), 0.0);
+    }
+}
@@ -0,0 +1,38 @@
+//! Compatibility fallbacks for `egui` integrations.
+use crate::mem;
+
+/// Enable or disable the layout fallback of slide presets for the given
+/// [`egui::Context`]. Disabled by default.
+///
+/// Some integrations handle layer transforms inconsistently. When enabled,
+/// [`presets::slide`](crate::presets::slide) and
+/// [`presets::slide_fade`](crate::presets::slide_fade) offset the contents by consuming
+/// layout space before them, rather than with a layer transform. This changes how
+/// slides look:
+///
+/// - Contents reflow rather than overlay. Sliding contents push the widgets after them,
+///   and the animation scope grows by the offset.
+/// - Only the offset along the main direction of the layout is applied, e.g. the
+///   vertical offset in a vertical layout. The cross axis offset is ignored.
+/// - Negative offsets consume negative space, pulling the contents back over the
+///   widgets before them.
+/// - Offsets are rounded to whole ui points, as with [`egui::Ui::add_space`].
+///
+/// Other presets (such as [`presets::scale`](crate::presets::scale)) still use layer
+/// transforms.
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// # let ctx = egui::Context::default();
+/// set_slide_fallback(&ctx, true);
+/// ```
+pub fn set_slide_fallback(ctx: &egui::Context, enabled: bool) {
+    mem::set_slide_fallback(ctx, enabled);
+}
+
+/// Returns `true` if the layout fallback of slide presets is enabled for the given
+/// [`egui::Context`]. See [`set_slide_fallback`].
+pub fn slide_fallback(ctx: &egui::Context) -> bool {
+    mem::get_slide_fallback(ctx)
+}
@@ -0,0 +1,62 @@
+//! Stepping through the values of animated state.
+
+/// A value that steps forward and back through a fixed sequence of values, wrapping
+/// around at either end, such as the options of a menu.
+///
+/// Implemented for field-less enums with the [`cycle!`](crate::cycle) macro, stepping
+/// through variants in declaration order. Pairs with [`animate`](crate::animate) to
+/// animate each step.
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// cycle! {
+///     #[derive(Debug, Default, Clone, Copy, PartialEq)]
+///     enum Color {
+///         #[default]
+///         Red,
+///         Green,
+///         Blue,
+///     }
+/// }
+///
+/// let mut color = Color::Blue;
+/// color.next();
+/// assert_eq!(color, Color::Red);
+/// color.prev();
+/// assert_eq!(color, Color::Blue);
+/// ```
+pub trait Cycle {
+    /// Step to the next value, wrapping around to the first.
+    fn next(&mut self);
+
+    /// Step to the previous value, wrapping around to the last.
+    fn prev(&mut self);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::cycle! {
+        #[derive(Debug, PartialEq)]
+        enum TestState {
+            First,
+            Second,
+            Third,
+        }
+    }
+
+    #[test]
+    fn test_cycle() {
+        let mut state = TestState::First;
+
+        state.next();
+        assert_eq!(state, TestState::Second);
+        state.next();
+        state.next();
+        assert_eq!(state, TestState::First);
+        state.prev();
+        assert_eq!(state, TestState::Third);
+    }
+}
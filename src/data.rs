@@ -0,0 +1,95 @@
+//! Per-animation scratch data, for animation functions that keep state between frames.
+use std::any::Any;
+
+use crate::mem;
+
+/// Call `f` with the scratch data of type `D` of the animation currently being applied,
+/// returning the result. Should be called from within an animation function.
+///
+/// The data is inserted as `D::default()` on first use, and kept between frames of the
+/// animation. It is cleared with the rest of the animation memory once the animation
+/// finishes, or once it is no longer animated. Each animation keeps one value per type,
+/// shared by its *out* and *in* segments. Outside of an animation function, `f` is
+/// called with a temporary default.
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// // Fade in, following the normal with a damped spring.
+/// const SPRING_IN: Animation = Animation::new_in(0.5, |ui, normal| {
+///     let position = animation_data(ui, |(position, velocity): &mut (f32, f32)| {
+///         *velocity = (*velocity + (normal - *position) * 0.3) * 0.8;
+///         *position += *velocity;
+///         *position
+///     });
+///     ui.set_opacity(position.clamp(0.0, 1.0));
+/// });
+/// ```
+pub fn animation_data<D: 'static + Any + Clone + Send + Sync + Default, R>(
+    ui: &egui::Ui,
+    f: impl FnOnce(&mut D) -> R,
+) -> R {
+    match mem::get_animation_id(ui.ctx()) {
+        Some(id) => mem::with_data(ui.ctx(), id, f),
+        None => f(&mut D::default()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Animation, animate};
+
+    const COUNTED_ANIM: Animation = Animation::new(
+        1.0,
+        |ui, _| animation_data(ui, |frames: &mut u32| *frames += 1),
+        |ui, _| animation_data(ui, |frames: &mut u32| *frames += 1),
+    );
+
+    fn run_frame(ctx: &egui::Context, time: f64, value: i32) {
+        let input = egui::RawInput {
+            time: Some(time),
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                animate(ui, "test_anim", value, COUNTED_ANIM, |_, _| {});
+            });
+        });
+    }
+
+    #[test]
+    fn test_animation_data() {
+        let ctx = egui::Context::default();
+        let id = egui::Id::new("test_anim");
+        let frames = |ctx: &egui::Context| mem::with_data(ctx, id, |frames: &mut u32| *frames);
+
+        run_frame(&ctx, 0.0, 0);
+        run_frame(&ctx, 0.0, 1);
+        run_frame(&ctx, 0.25, 1);
+        run_frame(&ctx, 0.75, 1);
+        assert_eq!(frames(&ctx), 3);
+
+        run_frame(&ctx, 1.5, 1);
+        assert_eq!(frames(&ctx), 0);
+    }
+
+    #[test]
+    fn test_animation_data_outside_animation() {
+        let ctx = egui::Context::default();
+        let id = egui::Id::new("test_anim");
+        let frames = |ctx: &egui::Context| mem::with_data(ctx, id, |frames: &mut u32| *frames);
+
+        run_frame(&ctx, 0.0, 0);
+        run_frame(&ctx, 0.0, 1);
+        let _ = ctx.run(egui::RawInput::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                animate(ui, "test_anim", 1, COUNTED_ANIM, |_, _| {});
+                // The data of the last animation is not changed after `animate` returns.
+                animation_data(ui, |frames: &mut u32| *frames += 10);
+                assert_eq!(animation_data(ui, |frames: &mut u32| *frames), 0);
+            });
+        });
+        assert_eq!(frames(&ctx), 2);
+    }
+}
@@ -0,0 +1,62 @@
+//! Debug drawing of animation layers.
+use crate::RunState;
+use crate::mem;
+
+/// Enable or disable debug drawing for all animations of the given [`egui::Context`].
+///
+/// When enabled, the `clip_rect` of each animation layer is outlined, and the current
+/// [`RunState`] is drawn beside the animated contents.
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// # let ctx = egui::Context::default();
+/// set_debug_draw(&ctx, cfg!(debug_assertions));
+/// ```
+pub fn set_debug_draw(ctx: &egui::Context, enabled: bool) {
+    mem::set_debug_draw(ctx, enabled);
+}
+
+/// Returns `true` if debug drawing is enabled for the given [`egui::Context`].
+pub fn debug_draw(ctx: &egui::Context) -> bool {
+    mem::get_debug_draw(ctx)
+}
+
+/// Wrap `add_contents`, drawing the animation layer bounds and `run_state` after the
+/// contents are added if debug drawing is enabled.
+pub(crate) fn with_debug_draw<R>(
+    run_state: RunState,
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> impl FnOnce(&mut egui::Ui) -> R {
+    move |ui| {
+        let inner = add_contents(ui);
+        if debug_draw(ui.ctx()) {
+            draw_layer(ui, &run_state);
+        }
+        inner
+    }
+}
+
+/// Outline the `clip_rect` of the animation layer, and draw the `run_state`.
+fn draw_layer(ui: &egui::Ui, run_state: &RunState) {
+    let color = egui::Color32::RED;
+    let transform = ui
+        .ctx()
+        .layer_transform_to_global(ui.layer_id())
+        .unwrap_or_default();
+    let painter = ui.ctx().debug_painter();
+
+    painter.rect_stroke(
+        transform * ui.clip_rect(),
+        0.0,
+        egui::Stroke::new(1.0, color),
+        egui::StrokeKind::Inside,
+    );
+    painter.text(
+        (transform * ui.min_rect()).right_top(),
+        egui::Align2::LEFT_TOP,
+        format!("{run_state:?}"),
+        egui::FontId::monospace(10.0),
+        color,
+    );
+}
@@ -0,0 +1,69 @@
+//! An app-wide default animation.
+use std::any::Any;
+
+use crate::{Animation, AnimationResult, animate, mem};
+
+/// Set the default animation for the given [`egui::Context`], used by [`animate_default`].
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// # let ctx = egui::Context::default();
+/// set_default_animation(&ctx, Animation::crossfade(0.2));
+/// ```
+pub fn set_default_animation(ctx: &egui::Context, animation: impl Into<Animation>) {
+    mem::set_default_animation(ctx, animation.into());
+}
+
+/// Get the default animation for the given [`egui::Context`]. Returns [`Animation::EMPTY`]
+/// if none is set. See [`set_default_animation`].
+pub fn default_animation(ctx: &egui::Context) -> Animation {
+    mem::get_default_animation(ctx).unwrap_or(Animation::EMPTY)
+}
+
+/// Create an animation that transitions between changes of the given `value`, with the
+/// default animation of the [`egui::Context`]. See [`animate`] and [`set_default_animation`].
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # let mut my_state: u32 = 0;
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// set_default_animation(ctx, Animation::crossfade(0.2));
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// animate_default(ui, "my_anim", my_state, |ui, value| {
+///     ui.label(format!("Value is {}", value));
+/// });
+/// #
+/// # });
+/// # });
+/// ```
+pub fn animate_default<T: 'static + Any + Clone + Send + Sync + Default + PartialEq, R>(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    value: T,
+    add_contents: impl FnOnce(&mut egui::Ui, T) -> R,
+) -> AnimationResult {
+    let animation = default_animation(ui.ctx());
+    animate(ui, id, value, animation, add_contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_animation() {
+        let ctx = egui::Context::default();
+        let animation = Animation::crossfade(0.2);
+
+        assert_eq!(default_animation(&ctx), Animation::EMPTY);
+        set_default_animation(&ctx, animation);
+        assert_eq!(default_animation(&ctx), animation);
+    }
+}
@@ -0,0 +1,160 @@
+//! Easing utilities, for use with the *normal* of animation functions.
+use egui::emath::easing::*;
+
+use crate::mem;
+
+/// An easing function, mapping a *normal* to an eased value.
+type EasingFn = fn(f32) -> f32;
+
+/// The bundled easing functions, by name.
+pub const EASINGS: &[(&str, EasingFn)] = &[
+    ("Linear", linear),
+    ("Quadratic in", quadratic_in),
+    ("Quadratic out", quadratic_out),
+    ("Quadratic in-out", quadratic_in_out),
+    ("Cubic in", cubic_in),
+    ("Cubic out", cubic_out),
+    ("Cubic in-out", cubic_in_out),
+    ("Sine in", sin_in),
+    ("Sine out", sin_out),
+    ("Sine in-out", sin_in_out),
+    ("Back in", back_in),
+    ("Back out", back_out),
+    ("Back in-out", back_in_out),
+    ("Bounce in", bounce_in),
+    ("Bounce out", bounce_out),
+    ("Bounce in-out", bounce_in_out),
+];
+
+/// The identifiers of the bundled easing functions, in the order of [`EASINGS`].
+const IDENTIFIERS: &[&str] = &[
+    "linear",
+    "quadratic_in",
+    "quadratic_out",
+    "quadratic_in_out",
+    "cubic_in",
+    "cubic_out",
+    "cubic_in_out",
+    "sine_in",
+    "sine_out",
+    "sine_in_out",
+    "back_in",
+    "back_out",
+    "back_in_out",
+    "bounce_in",
+    "bounce_out",
+    "bounce_in_out",
+];
+
+/// Get the bundled easing function of the given identifier, such as `"linear"` or
+/// `"cubic_in_out"`. Returns `None` for unknown identifiers.
+///
+/// Identifiers are the [`EASINGS`] names in snake case. Useful for resolving easing
+/// functions from serialized configs. See [`name_of`] for the reverse lookup.
+///
+/// # Example
+/// ```
+/// # use egui_animate::easing;
+/// let easing = easing::by_name("quadratic_in").unwrap();
+/// assert_eq!(easing(0.5), 0.25);
+/// assert_eq!(easing::name_of(easing), Some("quadratic_in"));
+/// ```
+pub fn by_name(name: &str) -> Option<EasingFn> {
+    IDENTIFIERS
+        .iter()
+        .position(|identifier| *identifier == name)
+        .map(|index| EASINGS[index].1)
+}
+
+/// Get the identifier of the given bundled `easing` function. Returns `None` for other
+/// functions. See [`by_name`].
+///
+/// Functions are compared by address, with the caveats of
+/// [`Animation`](crate::Animation#equality) equality.
+pub fn name_of(easing: EasingFn) -> Option<&'static str> {
+    EASINGS
+        .iter()
+        .position(|(_, bundled)| std::ptr::fn_addr_eq(*bundled, easing))
+        .map(|index| IDENTIFIERS[index])
+}
+
+/// Set the default easing for the given [`egui::Context`]. Defaults to [`linear`].
+///
+/// The default easing applies to segments that opt in with
+/// [`AnimationSegment::with_default_easing`] (or [`Animation::with_default_easing`]),
+/// such as [`presets::expand_height`], establishing a consistent motion feel across
+/// animations from one place. Segments read the default easing when animating, rather
+/// than when constructed, so the same animation eases differently in contexts with
+/// different default easings.
+///
+/// [`AnimationSegment::with_default_easing`]: crate::AnimationSegment::with_default_easing
+/// [`Animation::with_default_easing`]: crate::Animation::with_default_easing
+/// [`presets::expand_height`]: crate::presets::expand_height
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// # let ctx = egui::Context::default();
+/// easing::set_default_easing(&ctx, egui::emath::easing::cubic_in_out);
+/// ```
+pub fn set_default_easing(ctx: &egui::Context, easing: EasingFn) {
+    mem::set_default_easing(ctx, easing);
+}
+
+/// Get the default easing for the given [`egui::Context`]. Returns
+/// [`linear`] if none is set. See [`set_default_easing`].
+pub fn default_easing(ctx: &egui::Context) -> EasingFn {
+    mem::get_default_easing(ctx).unwrap_or(linear)
+}
+
+/// Sample the given `easing` function at `steps` evenly spaced inputs across `0.0..=1.0`,
+/// returning `(input, output)` pairs. Useful for plotting easing curves.
+///
+/// # Example
+/// ```
+/// # use egui_animate::easing::sample_easing;
+/// let points = sample_easing(egui::emath::easing::quadratic_in, 3);
+/// assert_eq!(points, vec![(0.0, 0.0), (0.5, 0.25), (1.0, 1.0)]);
+/// ```
+pub fn sample_easing(easing: fn(f32) -> f32, steps: usize) -> Vec<(f32, f32)> {
+    let last = steps.saturating_sub(1).max(1) as f32;
+
+    (0..steps)
+        .map(|step| step as f32 / last)
+        .map(|input| (input, easing(input)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_easing_linear() {
+        let points = sample_easing(|x| x, 11);
+
+        assert_eq!(points.len(), 11);
+        for (step, (input, output)) in points.into_iter().enumerate() {
+            assert_eq!(input, step as f32 / 10.0);
+            assert_eq!(input, output);
+        }
+    }
+
+    #[test]
+    fn test_by_name() {
+        assert_eq!(IDENTIFIERS.len(), EASINGS.len());
+        for (identifier, (name, _)) in IDENTIFIERS.iter().zip(EASINGS) {
+            assert_eq!(*identifier, name.to_lowercase().replace([' ', '-'], "_"));
+            assert_eq!(by_name(identifier).and_then(name_of), Some(*identifier));
+        }
+        assert!(by_name("Linear").is_none());
+        assert_eq!(name_of(|x| x * 2.0), None);
+    }
+
+    #[test]
+    fn test_sample_easing_steps() {
+        assert!(sample_easing(|x| x, 0).is_empty());
+        assert_eq!(sample_easing(|x| x, 1), vec![(0.0, 0.0)]);
+        assert_eq!(sample_easing(|x| x, 2), vec![(0.0, 0.0), (1.0, 1.0)]);
+    }
+}
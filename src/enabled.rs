@@ -0,0 +1,29 @@
+//! Enabling and disabling individual animations.
+use crate::mem;
+
+/// Enable or disable the animation of the given `id` for the given [`egui::Context`].
+/// Animations are enabled by default.
+///
+/// While disabled, [`animate`](crate::animate) and its variants (including
+/// [`animate_number`](crate::animate_number), [`animate_rect`](crate::animate_rect) and
+/// [`animate_progress`](crate::animate_progress)) show the current value immediately,
+/// without changing the call site, such as for letting users turn off specific
+/// animations or comparing motion designs. Disabling a running animation finishes it on
+/// its next frame, clearing its in-flight state.
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// # let ctx = egui::Context::default();
+/// set_animation_enabled(&ctx, "menu_anim", false);
+/// assert!(!animation_enabled(&ctx, "menu_anim"));
+/// ```
+pub fn set_animation_enabled(ctx: &egui::Context, id: impl Into<egui::Id>, enabled: bool) {
+    mem::set_animation_disabled(ctx, id.into(), !enabled);
+}
+
+/// Returns `true` if the animation of the given `id` is enabled for the given
+/// [`egui::Context`]. See [`set_animation_enabled`].
+pub fn animation_enabled(ctx: &egui::Context, id: impl Into<egui::Id>) -> bool {
+    !mem::get_animation_disabled(ctx, id.into())
+}
@@ -0,0 +1,111 @@
+//! Method-call syntax for animating an `egui::Ui`.
+use std::any::Any;
+
+use crate::{Animation, AnimationResult, RunState, animate, run_state};
+
+/// Extension methods on [`egui::Ui`], for animating in builder-style `egui` code.
+///
+/// Each method calls the matching free function, such as [`animate`] and [`run_state`].
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # const FADE_ANIM: Animation = Animation::EMPTY;
+/// # let mut my_state: u32 = 0;
+/// #
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// ui.animate("my_fade", my_state, FADE_ANIM, |ui, value| {
+///     ui.label(format!("Value is {}", value));
+/// });
+///
+/// if ui.animation_run_state("my_fade", FADE_ANIM).is_running() {
+///     ui.label("Animation running...");
+/// }
+/// #
+/// # });
+/// # });
+/// ```
+pub trait AnimateExt {
+    /// Create an animation that transitions between changes of the given `value`. See
+    /// [`animate`].
+    fn animate<T: 'static + Any + Clone + Send + Sync + Default + PartialEq, R>(
+        &mut self,
+        id: impl Into<egui::Id>,
+        value: T,
+        animation: impl Into<Animation>,
+        add_contents: impl FnOnce(&mut egui::Ui, T) -> R,
+    ) -> AnimationResult;
+
+    /// Get the [`RunState`] for the animation of the given `id`. See [`run_state`].
+    fn animation_run_state(
+        &mut self,
+        id: impl Into<egui::Id>,
+        animation: impl Into<Animation>,
+    ) -> RunState;
+}
+
+impl AnimateExt for egui::Ui {
+    fn animate<T: 'static + Any + Clone + Send + Sync + Default + PartialEq, R>(
+        &mut self,
+        id: impl Into<egui::Id>,
+        value: T,
+        animation: impl Into<Animation>,
+        add_contents: impl FnOnce(&mut egui::Ui, T) -> R,
+    ) -> AnimationResult {
+        animate(self, id, value, animation, add_contents)
+    }
+
+    fn animation_run_state(
+        &mut self,
+        id: impl Into<egui::Id>,
+        animation: impl Into<Animation>,
+    ) -> RunState {
+        run_state(self, id, animation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_ANIM: Animation = Animation::new(1.0, |_, _| {}, |_, _| {});
+
+    #[test]
+    fn test_animate_ext() {
+        let ctx = egui::Context::default();
+        let run_frame = |time: f64, value: i32| {
+            let input = egui::RawInput {
+                time: Some(time),
+                ..Default::default()
+            };
+            let mut states = (RunState::None, RunState::None);
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let result = ui.animate("test_anim", value, TEST_ANIM, |_, _| {});
+                    states = (
+                        result.run_state,
+                        ui.animation_run_state("test_anim", TEST_ANIM),
+                    );
+                });
+            });
+            states
+        };
+
+        assert_eq!(run_frame(0.0, 0), (RunState::None, RunState::None));
+        assert_eq!(
+            run_frame(1.0, 1),
+            (RunState::OutSeg(0.0), RunState::OutSeg(0.0))
+        );
+        assert_eq!(
+            run_frame(1.75, 1),
+            (RunState::InSeg(0.5), RunState::InSeg(0.5))
+        );
+        assert_eq!(run_frame(2.0, 1), (RunState::None, RunState::None));
+    }
+}
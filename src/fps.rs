@@ -0,0 +1,162 @@
+//! Frame rate capping of animations.
+use crate::mem;
+
+/// Set the maximum frame rate (in frames per second) of all animations of the given
+/// [`egui::Context`]. Disabled with `None` (the default).
+///
+/// By default, running animations request a repaint every frame, animating at the full
+/// refresh rate of the display. With a maximum frame rate, animations advance in steps
+/// of `1.0 / max_fps` seconds, requesting repaints only when the next step is due, and
+/// rendering unchanged between steps. Useful for saving power on high refresh rate
+/// displays, where subtle animations look no different.
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// # let ctx = egui::Context::default();
+/// // Animate at no more than 60 fps.
+/// set_max_fps(&ctx, Some(60.0));
+/// ```
+pub fn set_max_fps(ctx: &egui::Context, max_fps: Option<f32>) {
+    mem::set_max_fps(ctx, max_fps);
+}
+
+/// Get the maximum frame rate (in frames per second) of all animations of the given
+/// [`egui::Context`]. See [`set_max_fps`].
+pub fn max_fps(ctx: &egui::Context) -> Option<f32> {
+    mem::get_max_fps(ctx)
+}
+
+/// Set the maximum frame rate (in frames per second) of all animations of the given
+/// [`egui::Context`] while the viewport is not focused. Disabled with `None` (the
+/// default).
+///
+/// Animations in background viewports, such as unfocused windows of multi-viewport
+/// apps, otherwise keep rendering at the full frame rate. The lower of the background
+/// frame rate and the maximum frame rate (see [`set_max_fps`]) applies. Animations in
+/// minimized viewports never request repaints, and resume once the viewport is shown.
+///
+/// The background frame rate only throttles repaints. Animation time keeps advancing in
+/// the steps of the maximum frame rate, so running animations do not jump when the
+/// viewport gains or loses focus.
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// # let ctx = egui::Context::default();
+/// // Animate at no more than 10 fps in unfocused windows.
+/// set_background_fps(&ctx, Some(10.0));
+/// ```
+pub fn set_background_fps(ctx: &egui::Context, background_fps: Option<f32>) {
+    mem::set_background_fps(ctx, background_fps);
+}
+
+/// Get the maximum frame rate (in frames per second) of all animations of the given
+/// [`egui::Context`] while the viewport is not focused. See [`set_background_fps`].
+pub fn background_fps(ctx: &egui::Context) -> Option<f32> {
+    mem::get_background_fps(ctx)
+}
+
+/// Get the minimum interval (in seconds) between animation frames of the current
+/// viewport. Returns `0.0` if the frame rate is not capped.
+pub(crate) fn frame_interval(ctx: &egui::Context) -> f64 {
+    let focused = ctx.input(|input| input.viewport().focused) != Some(false);
+    let background_fps = background_fps(ctx).filter(|_| !focused);
+
+    [max_fps(ctx), background_fps]
+        .into_iter()
+        .flatten()
+        .filter(|max_fps| *max_fps > 0.0)
+        .reduce(f32::min)
+        .map_or(0.0, |max_fps| 1.0 / max_fps as f64)
+}
+
+/// Request a repaint of the current viewport after the given `secs`, unless the viewport
+/// is minimized.
+pub(crate) fn request_repaint_after(ctx: &egui::Context, secs: f64) {
+    if ctx.input(|input| input.viewport().minimized) != Some(true) {
+        ctx.request_repaint_after_secs(secs.max(0.0) as f32);
+    }
+}
+
+/// Get the animation time for the given `current_time`, stepped in intervals of the
+/// maximum frame rate from the `start_time`. The steps do not depend on the focus of the
+/// viewport, so they stay the same for the whole animation.
+pub(crate) fn frame_time(ctx: &egui::Context, start_time: f64, current_time: f64) -> f64 {
+    let interval = max_fps(ctx)
+        .filter(|max_fps| *max_fps > 0.0)
+        .map_or(0.0, |max_fps| 1.0 / max_fps as f64);
+    match interval {
+        interval if interval > 0.0 => {
+            let steps = ((current_time - start_time) / interval).floor().max(0.0);
+            start_time + steps * interval
+        }
+        _ => current_time,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run a single frame with the given viewport state, returning the frame interval
+    /// and the requested repaint delay.
+    fn run_frame(
+        ctx: &egui::Context,
+        focused: bool,
+        minimized: bool,
+    ) -> (f64, std::time::Duration) {
+        let mut input = egui::RawInput::default();
+        let viewport = input.viewports.entry(egui::ViewportId::ROOT).or_default();
+        viewport.focused = Some(focused);
+        viewport.minimized = Some(minimized);
+
+        let mut interval = f64::NAN;
+        let output = ctx.run(input, |ctx| {
+            interval = frame_interval(ctx);
+            request_repaint_after(ctx, interval);
+        });
+        (
+            interval,
+            output.viewport_output[&egui::ViewportId::ROOT].repaint_delay,
+        )
+    }
+
+    #[test]
+    fn test_background_fps() {
+        let ctx = egui::Context::default();
+        set_max_fps(&ctx, Some(20.0));
+        set_background_fps(&ctx, Some(4.0));
+
+        assert_eq!(run_frame(&ctx, true, false).0, 0.05);
+        // Unfocused viewports use the lower frame rate.
+        let (interval, repaint_delay) = run_frame(&ctx, false, false);
+        assert_eq!(interval, 0.25);
+        assert!(repaint_delay <= std::time::Duration::from_secs_f32(0.25));
+        set_max_fps(&ctx, Some(2.0));
+        assert_eq!(run_frame(&ctx, false, false).0, 0.5);
+
+        // Minimized viewports do not repaint.
+        assert_eq!(run_frame(&ctx, false, true).1, std::time::Duration::MAX);
+    }
+
+    #[test]
+    fn test_frame_time_ignores_focus() {
+        let ctx = egui::Context::default();
+        set_max_fps(&ctx, Some(20.0));
+        set_background_fps(&ctx, Some(4.0));
+        let frame_time_at = |focused: bool| {
+            let mut input = egui::RawInput::default();
+            let viewport = input.viewports.entry(egui::ViewportId::ROOT).or_default();
+            viewport.focused = Some(focused);
+
+            let mut time = f64::NAN;
+            let _ = ctx.run(input, |ctx| time = frame_time(ctx, 1.0, 1.32));
+            time
+        };
+
+        // Stepped by the maximum frame rate, with or without focus.
+        assert!((frame_time_at(true) - 1.3).abs() < 1e-9);
+        assert!((frame_time_at(false) - 1.3).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,83 @@
+//! Animated transitions between images.
+use crate::{Animation, AnimationResult, animate_crossfade};
+
+/// Create an animation that transitions between changes of the given `texture`, showing
+/// an [`egui::Image`] of the given `size`.
+///
+/// With an overlapping animation such as [`Animation::crossfade`], the prior and new
+/// images are drawn over the same rect for the duration of the overlap, blending the
+/// images with the opacities of each segment. See [`animate_crossfade`].
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # let avatar = egui::TextureId::default();
+/// const CROSSFADE: Animation = Animation::crossfade(0.3);
+///
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// animate_image(ui, "avatar", avatar, egui::vec2(64.0, 64.0), CROSSFADE);
+/// #
+/// # });
+/// # });
+/// ```
+pub fn animate_image(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    texture: egui::TextureId,
+    size: egui::Vec2,
+    animation: impl Into<Animation>,
+) -> AnimationResult {
+    animate_crossfade(ui, id, texture, animation, |ui, texture| {
+        ui.add(egui::Image::new((texture, size)));
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run a single frame at the given `time`, returning the textures painted.
+    fn run_frame(ctx: &egui::Context, time: f64, texture: egui::TextureId) -> Vec<egui::TextureId> {
+        let input = egui::RawInput {
+            time: Some(time),
+            ..Default::default()
+        };
+        let output = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                animate_image(
+                    ui,
+                    "test_image",
+                    texture,
+                    egui::vec2(8.0, 8.0),
+                    Animation::crossfade(1.0),
+                );
+            });
+        });
+
+        let mut textures: Vec<_> = output
+            .shapes
+            .iter()
+            .map(|clipped| clipped.shape.texture_id())
+            .filter(|texture| *texture != egui::TextureId::default())
+            .collect();
+        textures.sort();
+        textures
+    }
+
+    #[test]
+    fn test_crossfade_images() {
+        let ctx = egui::Context::default();
+        let (a, b) = (egui::TextureId::User(1), egui::TextureId::User(2));
+
+        assert_eq!(run_frame(&ctx, 0.0, a), vec![a]);
+        run_frame(&ctx, 1.0, b);
+        assert_eq!(run_frame(&ctx, 1.5, b), vec![a, b]);
+        assert_eq!(run_frame(&ctx, 3.0, b), vec![b]);
+    }
+}
@@ -0,0 +1,169 @@
+//! A widget for live-tuning animations.
+use crate::easing::EASINGS;
+use crate::{Animation, AnimationSegment, RunState, run_state};
+
+/// The maximum segment duration (in seconds) selectable with the inspector sliders.
+const MAX_DURATION: f32 = 2.0;
+
+/// Show controls for tuning the given `animation` in place, with a progress bar of the
+/// running animation of the given `id`.
+///
+/// Shows the [`Animation::name`] if set, duration sliders and an easing dropdown for
+/// each segment, and an overlap slider. Selecting an easing remaps the *normal* of the
+/// segment with the easing function (see [`AnimationSegment::map_normal`]), keeping any
+/// keyframes of the segment. Returns the union of the control responses.
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # let mut my_state: u32 = 0;
+/// let mut my_anim = Animation::crossfade(0.3);
+/// #
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// inspector(ui, "my_anim", &mut my_anim);
+///
+/// animate(ui, "my_anim", my_state, my_anim, |ui, value| {
+///     // ...
+/// });
+/// #
+/// # });
+/// # });
+/// ```
+pub fn inspector(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    animation: &mut Animation,
+) -> egui::Response {
+    let id: egui::Id = id.into();
+
+    ui.group(|ui| {
+        if !animation.name().is_empty() {
+            ui.strong(animation.name());
+        }
+        let mut response = segment_controls(ui, id.with("out"), "Out", &mut animation.out_seg);
+        response |= segment_controls(ui, id.with("in"), "In", &mut animation.in_seg);
+
+        let max_overlap = animation.out_seg.duration.min(animation.in_seg.duration);
+        let mut overlap = animation.overlap();
+        let overlap_response = ui.add(
+            egui::Slider::new(&mut overlap, 0.0..=max_overlap)
+                .text("Overlap")
+                .suffix(" s"),
+        );
+        if overlap_response.changed() {
+            *animation = animation.with_overlap(overlap);
+        }
+        response |= overlap_response;
+
+        let run_state = run_state(ui, id, *animation);
+        ui.add(
+            egui::ProgressBar::new(progress(animation, &run_state)).text(match run_state {
+                RunState::OutSeg(normal) => format!("Out {normal:.2}"),
+                RunState::Hold(normal) => format!("Hold {normal:.2}"),
+                RunState::InSeg(normal) => format!("In {normal:.2}"),
+                RunState::None => "Idle".to_string(),
+            }),
+        );
+
+        response
+    })
+    .inner
+}
+
+/// Show the duration slider and easing dropdown for the given `segment`.
+fn segment_controls(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    label: &str,
+    segment: &mut AnimationSegment,
+) -> egui::Response {
+    ui.horizontal(|ui| {
+        let mut response = ui.add(
+            egui::Slider::new(&mut segment.duration, 0.0..=MAX_DURATION)
+                .text(format!("{label} duration"))
+                .suffix(" s"),
+        );
+
+        let selected = selected_easing(segment);
+        let combo = egui::ComboBox::from_id_salt(id)
+            .selected_text(selected.unwrap_or("Custom"))
+            .show_ui(ui, |ui| {
+                for (index, (name, _)) in EASINGS.iter().enumerate() {
+                    if ui.selectable_label(selected == Some(name), *name).clicked() {
+                        select_easing(segment, index);
+                    }
+                }
+            });
+        response |= combo.response;
+
+        response
+    })
+    .inner
+}
+
+/// Get the name of the [`EASINGS`] entry remapping the *normal* of the given `segment`.
+/// Returns `None` for other remapping functions.
+fn selected_easing(segment: &AnimationSegment) -> Option<&'static str> {
+    match segment.map_fn() {
+        None => Some(EASINGS[0].0),
+        Some(map_fn) => EASINGS
+            .iter()
+            .find(|(_, easing)| std::ptr::fn_addr_eq(*easing, map_fn))
+            .map(|(name, _)| *name),
+    }
+}
+
+/// Remap the *normal* of the given `segment` with the [`EASINGS`] entry of the given
+/// `index`. The first (linear) entry removes the remapping.
+fn select_easing(segment: &mut AnimationSegment, index: usize) {
+    segment.map_fn = match index {
+        0 => None,
+        index => Some(EASINGS[index].1),
+    };
+}
+
+/// Get the overall progress (`0.0` to `1.0`) of the `animation` for the given `run_state`.
+fn progress(animation: &Animation, run_state: &RunState) -> f32 {
+    run_state.progress(animation).unwrap_or(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress() {
+        let animation = Animation::new(2.0, |_, _| {}, |_, _| {});
+
+        assert_eq!(progress(&animation, &RunState::OutSeg(0.5)), 0.25);
+        assert_eq!(progress(&animation, &RunState::InSeg(0.5)), 0.75);
+        assert_eq!(progress(&animation, &RunState::None), 0.0);
+        let animation = animation.with_hold(2.0);
+        assert_eq!(progress(&animation, &RunState::Hold(0.5)), 0.5);
+        assert_eq!(progress(&animation, &RunState::InSeg(0.5)), 0.875);
+        assert_eq!(progress(&Animation::EMPTY, &RunState::InSeg(0.5)), 0.0);
+    }
+
+    #[test]
+    fn test_select_easing() {
+        const KEYFRAMES: &[(f32, f32)] = &[(0.0, 0.0), (1.0, 2.0)];
+        let mut segment = AnimationSegment::keyframes(1.0, KEYFRAMES, |_, _| {});
+        assert_eq!(selected_easing(&segment), Some(EASINGS[0].0));
+
+        select_easing(&mut segment, 1);
+        assert_eq!(selected_easing(&segment), Some(EASINGS[1].0));
+        // The keyframes are kept, and applied to the eased normal.
+        assert_eq!(segment.keyframe_table(), KEYFRAMES);
+        assert_eq!(segment.eased(0.5), 0.5);
+
+        select_easing(&mut segment, 0);
+        assert!(segment.map_fn().is_none());
+        assert_eq!(selected_easing(&segment.map_normal(|n| n)), None);
+    }
+}
@@ -0,0 +1,153 @@
+//! Animations triggered by `egui::Response` interactions.
+use crate::mem;
+use crate::{Animation, AnimationResult, animate};
+
+/// Create an animation that transitions when the hover state of the given `response`
+/// changes. The contents recieve `true` while the response is hovered.
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # const MY_ANIM: Animation = Animation::EMPTY;
+/// #
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// let response = ui.button("Hover me");
+/// animate_on_hovered(ui, "my_anim", &response, MY_ANIM, |ui, hovered| {
+///     if hovered {
+///         ui.label("Hovering!");
+///     }
+/// });
+/// #
+/// # });
+/// # });
+/// ```
+pub fn animate_on_hovered<R>(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    response: &egui::Response,
+    animation: impl Into<Animation>,
+    add_contents: impl FnOnce(&mut egui::Ui, bool) -> R,
+) -> AnimationResult {
+    animate(ui, id, response.hovered(), animation, add_contents)
+}
+
+/// Create an animation that transitions each time the given `response` is clicked.
+/// The contents recieve a latch, toggled by each click (initially `false`).
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # const MY_ANIM: Animation = Animation::EMPTY;
+/// #
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// let response = ui.button("Show details");
+/// animate_on_clicked(ui, "my_anim", &response, MY_ANIM, |ui, show| {
+///     if show {
+///         ui.label("Details");
+///     }
+/// });
+/// #
+/// # });
+/// # });
+/// ```
+pub fn animate_on_clicked<R>(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    response: &egui::Response,
+    animation: impl Into<Animation>,
+    add_contents: impl FnOnce(&mut egui::Ui, bool) -> R,
+) -> AnimationResult {
+    let id: egui::Id = id.into();
+
+    let latch = mem::toggle_latch(ui, id, response.clicked());
+    animate(ui, id, latch, animation, add_contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RunState;
+
+    const TEST_ANIM: Animation = Animation::new(1.0, |_, _| {}, |_, _| {});
+
+    /// Run a single frame at the given `time` with the given input `events`, returning
+    /// the button rect, the latch and the animation result.
+    fn run_frame(
+        ctx: &egui::Context,
+        time: f64,
+        events: Vec<egui::Event>,
+    ) -> (egui::Rect, bool, AnimationResult) {
+        let mut output = None;
+        let input = egui::RawInput {
+            time: Some(time),
+            events,
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let response = ui.button("Toggle");
+                let mut latch = false;
+                let result = animate_on_clicked(ui, "test_latch", &response, TEST_ANIM, |_, l| {
+                    latch = l;
+                });
+                output = Some((response.rect, latch, result));
+            });
+        });
+        output.unwrap()
+    }
+
+    /// Click the button at the given `pos` over two frames from the given `time`,
+    /// returning the latch and the animation result of the release frame.
+    fn click(ctx: &egui::Context, time: f64, pos: egui::Pos2) -> (bool, AnimationResult) {
+        let button = |pressed| egui::Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed,
+            modifiers: Default::default(),
+        };
+        run_frame(
+            ctx,
+            time,
+            vec![egui::Event::PointerMoved(pos), button(true)],
+        );
+        let (_, latch, result) = run_frame(ctx, time + 0.1, vec![button(false)]);
+        (latch, result)
+    }
+
+    #[test]
+    fn test_animate_on_clicked() {
+        let ctx = egui::Context::default();
+
+        let (rect, latch, result) = run_frame(&ctx, 0.0, vec![]);
+        assert!(!latch);
+        assert_eq!(result.run_state, RunState::None);
+
+        // Each click toggles the latch and runs the animation.
+        let (latch, result) = click(&ctx, 1.0, rect.center());
+        // The contents show the prior latch until the out segment finishes.
+        assert!(!latch);
+        assert!(result.just_started);
+        let (_, latch, result) = run_frame(&ctx, 3.0, vec![]);
+        assert!(latch);
+        assert_eq!(result.run_state, RunState::None);
+
+        let (latch, result) = click(&ctx, 4.0, rect.center());
+        assert!(latch);
+        assert!(result.just_started);
+        let (_, latch, result) = run_frame(&ctx, 6.0, vec![]);
+        assert!(!latch);
+        assert_eq!(result.run_state, RunState::None);
+    }
+}
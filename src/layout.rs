@@ -0,0 +1,118 @@
+//! Animations that interact with the layout of the parent `egui::Ui`.
+use std::any::Any;
+
+use crate::mem;
+use crate::{Animation, AnimationResult, animate};
+
+/// Create an animation that transitions between changes of the given `value`, reserving
+/// layout space for the largest size of the contents for the duration of the animation.
+///
+/// Layout containers such as [`egui::Grid`] size rows and columns from the contents of
+/// each cell. As [`animate`] renders the prior value during the *out* segment, the
+/// layout reflows when the new value is rendered. Transforms applied to the animation
+/// layer are visual only, and never affect layout. Reserving the largest size keeps the
+/// surrounding cells from shrinking and regrowing mid-animation.
+///
+/// The size is measured from the contents rendered on prior passes, so the layout grows
+/// at most once while animating, when a larger new value is first rendered, and settles
+/// to the size of the new value once the animation completes.
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # const MY_ANIM: Animation = Animation::EMPTY;
+/// # let mut my_state: u32 = 0;
+/// #
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// egui::Grid::new("my_grid").show(ui, |ui| {
+///     ui.label("Value");
+///     animate_in_grid(ui, "my_anim", my_state, MY_ANIM, |ui, value| {
+///         ui.label(format!("{value}"));
+///     });
+///     ui.end_row();
+/// });
+/// #
+/// # });
+/// # });
+/// ```
+pub fn animate_in_grid<T: 'static + Any + Clone + Send + Sync + Default + PartialEq, R>(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    value: T,
+    animation: impl Into<Animation>,
+    add_contents: impl FnOnce(&mut egui::Ui, T) -> R,
+) -> AnimationResult {
+    let id: egui::Id = id.into();
+
+    let reserved_size = mem::get_reserved_size(ui, id);
+    let egui::InnerResponse { inner, response } = ui.scope(|ui| {
+        if let Some(reserved_size) = reserved_size {
+            ui.set_min_size(reserved_size);
+        }
+        animate(ui, id, value, animation, add_contents)
+    });
+
+    match inner.run_state.is_running() {
+        true => {
+            let size = response.rect.size();
+            let reserved_size = reserved_size.map_or(size, |reserved| reserved.max(size));
+            mem::insert_reserved_size(ui, id, reserved_size);
+        }
+        false => mem::clear_reserved_size(ui, id),
+    }
+
+    inner
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_ANIM: Animation = Animation::new(1.0, |_, _| {}, |_, _| {});
+
+    /// Run a single frame at the given `time`, returning the width of the contents and
+    /// the number of times the contents were added.
+    fn run_frame(ctx: &egui::Context, time: f64, value: &'static str) -> (f32, usize) {
+        let input = egui::RawInput {
+            time: Some(time),
+            ..Default::default()
+        };
+        let (mut width, mut calls) = (0.0, 0);
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                width = ui
+                    .horizontal(|ui| {
+                        animate_in_grid(ui, "test_anim", value, TEST_ANIM, |ui, value| {
+                            calls += 1;
+                            ui.label(value);
+                        });
+                    })
+                    .response
+                    .rect
+                    .width();
+            });
+        });
+        (width, calls)
+    }
+
+    #[test]
+    fn test_reserve_largest_size() {
+        let ctx = egui::Context::default();
+        let (wide, calls) = run_frame(&ctx, 0.0, "A much wider value");
+        assert_eq!(calls, 1);
+
+        assert_eq!(run_frame(&ctx, 1.0, "B"), (wide, 1));
+        // The size of the wider prior value is kept while the new value renders.
+        assert_eq!(run_frame(&ctx, 1.75, "B"), (wide, 1));
+        run_frame(&ctx, 2.0, "B");
+        let (narrow, calls) = run_frame(&ctx, 2.5, "B");
+        assert!(narrow < wide);
+        assert_eq!(calls, 1);
+    }
+}
@@ -7,6 +7,7 @@
 //! - *Out* animations for hiding `egui::Ui` elements or variables.
 //! - *In* animations for presenting `egui::Ui` elements or variables.
 //! - Individual durations for *out*/*in* animation segments.
+//! - Overlapping *out*/*in* segments for cross-fade transitions.
 //! - Direct access to a scoped `&mut egui::Ui` for custom animations.
 //!
 //! ## Functionality
@@ -164,6 +165,22 @@
 //! # });
 //! ```
 //!
+//! ## Threading
+//!
+//! Animation state is kept within the memory of the [`egui::Context`], and animations
+//! are applied on the UI thread. The `Send + Sync` bounds of animated values are
+//! required by the `egui` memory. To trigger animations from other threads (e.g. network
+//! events or timers), set target values with [`AnimationTargets`], drained each frame by
+//! [`animate_targeted`].
+//!
+//! ## Prelude
+//!
+//! Import the [`prelude`] for the common types, functions and macros.
+//!
+//! ```
+//! use egui_animate::prelude::*;
+//! ```
+//!
 //! ## Examples
 //!
 //! Name | Description
@@ -171,14 +188,86 @@
 //! `showcase` | Various example animations.
 //! `menu` | A minimal dynamic "Main Menu" example.
 //! `variable` | Dynamic increment/decrement animations.
+//! `chain` | Staging animations across elements.
 //!
 //! ```bash
 //! cargo run --example [EXAMPLE]
 //! ```
+mod macros;
 mod mem;
 
+mod accessibility;
 mod anim;
+mod budget;
+mod chain;
+mod clock;
+mod collapsible;
+mod compat;
+mod cycle;
+mod data;
+mod debug;
+mod default;
+pub mod easing;
+mod enabled;
+mod ext;
+mod fps;
+mod image;
+mod inspector;
+mod interact;
+mod layout;
+mod marks;
+mod number;
+mod option;
+pub mod prelude;
+pub mod presets;
+mod progress;
+mod queue;
+mod rect;
+mod secs;
+mod seed;
+mod spinner;
 mod state;
+mod stats;
+mod targets;
+mod transition;
+mod typewriter;
+mod visuals;
 
+pub use accessibility::{hide_while_animating, set_hide_while_animating};
 pub use anim::{Animation, AnimationSegment};
-pub use state::{RunState, animate, run_state};
+pub use budget::{frame_budget, set_frame_budget};
+pub use chain::after;
+pub use clock::AnimationClock;
+pub use collapsible::collapsible_animated;
+pub use compat::{set_slide_fallback, slide_fallback};
+pub use cycle::Cycle;
+pub use data::animation_data;
+pub use debug::{debug_draw, set_debug_draw};
+pub use default::{animate_default, default_animation, set_default_animation};
+pub use enabled::{animation_enabled, set_animation_enabled};
+pub use ext::AnimateExt;
+pub use fps::{background_fps, max_fps, set_background_fps, set_max_fps};
+pub use image::animate_image;
+pub use inspector::inspector;
+pub use interact::{animate_on_clicked, animate_on_hovered};
+pub use layout::animate_in_grid;
+pub use marks::{Phase, animate_at};
+pub use number::animate_number;
+pub use option::animate_option;
+pub use progress::progress_bar;
+pub use queue::{AnimationQueue, animate_queued};
+pub use rect::animate_rect;
+pub use secs::{InvalidSecs, Secs};
+pub use seed::{animation_seed, jitter};
+pub use spinner::{spinner_animated, spinner_arc};
+pub use state::{
+    AnimationResult, RunState, animate, animate_by_frames, animate_crossfade, animate_progress,
+    animate_salted, animate_with_on_frame, any_animation_running, disabled_while_animating, driver,
+    lock_during_animation, next_repaint_after, previous_value, retrigger_animation, run_state,
+    run_state_eased, running_animations,
+};
+pub use stats::{AnimationStats, animation_stats};
+pub use targets::{AnimationTargets, animate_targeted};
+pub use transition::{Transition, animate_ordered, animate_state_machine, animate_transition};
+pub use typewriter::typewriter;
+pub use visuals::interpolate_visuals;
@@ -0,0 +1,263 @@
+/// Combine animation functions, segments or animations into a single definition.
+///
+/// As animation functions are plain `fn` pointers, combined functions are expanded
+/// into a new non-capturing closure. All arguments must therefore be paths or
+/// constant expressions, such as `fn` items and `const` definitions.
+///
+/// - `combine!(a, b, ..)` combines `fn(&mut egui::Ui, f32)` functions.
+/// - `combine!(segment: a, b, ..)` combines [`AnimationSegment`](crate::AnimationSegment)s.
+/// - `combine!(animation: a, b, ..)` combines [`Animation`](crate::Animation)s by segment.
+///
+/// Combined functions are called in the given order with the same *normal*. When
+/// combining segments or animations, the **longest** duration (and overlap and hold)
+/// wins, with shorter segments stretched to match.
+///
+/// Each combined segment keeps its own keyframes, remapping, clamping and layered
+/// functions. The combined segment uses a layer if any of its segments does, while the
+/// [`name`](crate::Animation::name) of combined animations is dropped.
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// fn fade(ui: &mut egui::Ui, normal: f32) {
+///     ui.set_opacity(normal);
+/// }
+/// fn slide(ui: &mut egui::Ui, normal: f32) {
+///     let translation = egui::vec2(10.0 - normal * 10.0, 0.0);
+///     let transform = egui::emath::TSTransform::from_translation(translation);
+///     ui.ctx().set_transform_layer(ui.layer_id(), transform);
+/// }
+///
+/// // Combine functions.
+/// const FADE_SLIDE_IN: Animation = Animation::new_in(0.3, combine!(fade, slide));
+///
+/// // Combine animations.
+/// const FADE_IN: Animation = Animation::new_in(0.3, fade);
+/// const SLIDE_IN: Animation = Animation::new_in(0.5, slide);
+/// const COMBINED: Animation = combine!(animation: FADE_IN, SLIDE_IN);
+///
+/// assert_eq!(COMBINED.duration(), 0.5);
+///
+/// // The longest hold wins.
+/// const HELD: Animation = combine!(animation: FADE_IN.with_hold(0.2), SLIDE_IN);
+/// assert_eq!(HELD.hold_dur(), 0.2);
+/// ```
+#[macro_export]
+macro_rules! combine {
+    (segment: $($segment:expr),+ $(,)?) => {{
+        let mut duration = 0.0_f32;
+        let mut uses_layer = false;
+        $(
+            duration = duration.max($segment.duration);
+            uses_layer = uses_layer || $segment.uses_layer();
+        )+
+
+        $crate::AnimationSegment::new(duration, |ui: &mut egui::Ui, normal: f32| {
+            $($segment.apply(ui, normal);)+
+        })
+        .with_layer(uses_layer)
+    }};
+    (animation: $($animation:expr),+ $(,)?) => {{
+        let mut overlap = 0.0_f32;
+        let mut hold_dur = 0.0_f32;
+        $(
+            overlap = overlap.max($animation.overlap());
+            hold_dur = hold_dur.max($animation.hold_dur());
+        )+
+
+        $crate::Animation::from_segments(
+            $crate::combine!(segment: $($animation.out_seg),+),
+            $crate::combine!(segment: $($animation.in_seg),+),
+        )
+        .with_overlap(overlap)
+        .with_hold(hold_dur)
+    }};
+    ($($anim_fn:expr),+ $(,)?) => {
+        |ui: &mut egui::Ui, normal: f32| {
+            $($anim_fn(ui, normal);)+
+        }
+    };
+}
+
+/// Define an [`Animation`](crate::Animation) from inline *out*/*in* closures.
+///
+/// Each closure is expanded into a generated `fn` item, allowing the result to be used
+/// in `const` definitions. As with `fn` items, closures cannot capture their environment.
+/// The `duration` is the total duration, split over both segments as in
+/// [`Animation::new`](crate::Animation::new).
+///
+/// - `animation! { out: .., in: .., duration: .. }` defines both segments.
+/// - `animation! { out: .., duration: .. }` defines only the *out* segment.
+/// - `animation! { in: .., duration: .. }` defines only the *in* segment.
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// const FADE: Animation = animation! {
+///     out: |ui, normal| ui.set_opacity(1.0 - normal),
+///     in: |ui, normal| ui.set_opacity(normal),
+///     duration: 0.3,
+/// };
+///
+/// const FADE_IN: Animation = animation! {
+///     in: |ui, normal| ui.set_opacity(normal),
+///     duration: 0.3,
+/// };
+///
+/// assert_eq!(FADE.duration(), FADE_IN.duration());
+/// ```
+#[macro_export]
+macro_rules! animation {
+    (
+        out: |$out_ui:pat_param, $out_normal:pat_param| $out_body:expr,
+        in: |$in_ui:pat_param, $in_normal:pat_param| $in_body:expr,
+        duration: $duration:expr $(,)?
+    ) => {{
+        fn out_fn($out_ui: &mut egui::Ui, $out_normal: f32) {
+            $out_body;
+        }
+        fn in_fn($in_ui: &mut egui::Ui, $in_normal: f32) {
+            $in_body;
+        }
+        $crate::Animation::new($duration, out_fn, in_fn)
+    }};
+    (
+        out: |$out_ui:pat_param, $out_normal:pat_param| $out_body:expr,
+        duration: $duration:expr $(,)?
+    ) => {{
+        fn out_fn($out_ui: &mut egui::Ui, $out_normal: f32) {
+            $out_body;
+        }
+        $crate::Animation::new_out($duration, out_fn)
+    }};
+    (
+        in: |$in_ui:pat_param, $in_normal:pat_param| $in_body:expr,
+        duration: $duration:expr $(,)?
+    ) => {{
+        fn in_fn($in_ui: &mut egui::Ui, $in_normal: f32) {
+            $in_body;
+        }
+        $crate::Animation::new_in($duration, in_fn)
+    }};
+}
+
+/// Reverse an animation function, passing `1.0 - normal` to the given function.
+///
+/// Expands into a new non-capturing closure, typically used to define an *out* function
+/// from an *in* function. The argument must be a path or constant expression, as with
+/// [`combine!`].
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// fn fade_in(ui: &mut egui::Ui, normal: f32) {
+///     ui.set_opacity(normal);
+/// }
+///
+/// const FADE: Animation = Animation::new(0.3, reverse!(fade_in), fade_in);
+/// ```
+#[macro_export]
+macro_rules! reverse {
+    ($anim_fn:expr) => {
+        |ui: &mut egui::Ui, normal: f32| $anim_fn(ui, 1.0 - normal)
+    };
+}
+
+/// Define a field-less enum implementing [`Cycle`](crate::Cycle), stepping through the
+/// variants in declaration order.
+///
+/// Wraps the enum definition, with attributes (such as derives) passed through.
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// cycle! {
+///     #[derive(Default, Clone, Copy, PartialEq)]
+///     pub enum Difficulty {
+///         #[default]
+///         Easy,
+///         Normal,
+///         Hard,
+///     }
+/// }
+///
+/// let mut difficulty = Difficulty::Hard;
+/// difficulty.next();
+/// assert!(difficulty == Difficulty::Easy);
+/// ```
+#[macro_export]
+macro_rules! cycle {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant),+
+        }
+
+        impl $crate::Cycle for $name {
+            fn next(&mut self) {
+                let variants = [$($name::$variant),+];
+                let len = variants.len();
+                let index = variants
+                    .iter()
+                    .position(|variant| ::std::mem::discriminant(variant) == ::std::mem::discriminant(self))
+                    .unwrap_or_default();
+                if let Some(next) = variants.into_iter().nth((index + 1) % len) {
+                    *self = next;
+                }
+            }
+
+            fn prev(&mut self) {
+                let variants = [$($name::$variant),+];
+                let len = variants.len();
+                let index = variants
+                    .iter()
+                    .position(|variant| ::std::mem::discriminant(variant) == ::std::mem::discriminant(self))
+                    .unwrap_or_default();
+                if let Some(prev) = variants.into_iter().nth((index + len - 1) % len) {
+                    *self = prev;
+                }
+            }
+        }
+    };
+}
+
+/// Create a unique [`egui::Id`] from the source location of the macro call.
+///
+/// Avoids inventing unique string names for each animation. The id is derived from
+/// `file!()`, `line!()` and `column!()`, so it is stable across frames, but shared by
+/// every call from the same location (e.g. within a loop or a reusable component). For
+/// repeated calls, combine it with an index via [`egui::Id::with`], or use
+/// [`animate_salted`](crate::animate_salted).
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # const FADE_ANIM: Animation = Animation::EMPTY;
+/// # let mut my_state: u32 = 0;
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// animate(ui, anim_id!(), my_state, FADE_ANIM, |ui, value| {
+///     ui.label(format!("Value is {}", value));
+/// });
+/// #
+/// # });
+/// # });
+///
+/// assert_ne!(anim_id!(), anim_id!());
+/// ```
+#[macro_export]
+macro_rules! anim_id {
+    () => {
+        egui::Id::new((file!(), line!(), column!()))
+    };
+}
@@ -0,0 +1,149 @@
+//! One-shot triggers at specific *normals* of an animation.
+use std::any::Any;
+
+use crate::mem;
+use crate::{Animation, AnimationResult, animate_with_on_frame};
+
+/// An animation segment, used to place marks with [`animate_at`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Phase {
+    /// The *out* segment.
+    Out,
+    /// The *in* segment.
+    In,
+}
+
+impl Phase {
+    /// Get the position of the `normal` within the animation, matching
+    /// [`RunState::signed_progress`](crate::RunState::signed_progress).
+    fn position(self, normal: f32) -> f32 {
+        match self {
+            Phase::Out => normal - 1.0,
+            Phase::In => normal,
+        }
+    }
+}
+
+/// Create an animation that transitions between changes of the given `value`, calling
+/// `on_mark` with the index of each of the given `marks` on the frame the animation
+/// crosses it. See [`animate`](crate::animate).
+///
+/// Each mark is a [`Phase`] and a *normal* within that segment. Marks are fired once
+/// per animation, in the order given, after `add_contents`. Marks skipped by a long
+/// frame still fire, and remaining marks fire on the frame the animation finishes. The
+/// *in* segment of overlapping animations begins crossing marks once the *out* segment
+/// finishes.
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # const FADE_ANIM: Animation = Animation::EMPTY;
+/// # let mut my_state: u32 = 0;
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// animate_at(
+///     ui,
+///     "my_fade",
+///     my_state,
+///     FADE_ANIM,
+///     &[(Phase::In, 0.5)],
+///     |ui, value| {
+///         ui.label(format!("Value is {}", value));
+///     },
+///     |index| {
+///         // Spawn a ripple halfway through the in segment.
+///     },
+/// );
+/// #
+/// # });
+/// # });
+/// ```
+pub fn animate_at<T: 'static + Any + Clone + Send + Sync + Default + PartialEq, R>(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    value: T,
+    animation: impl Into<Animation>,
+    marks: &[(Phase, f32)],
+    add_contents: impl FnOnce(&mut egui::Ui, T) -> R,
+    mut on_mark: impl FnMut(usize),
+) -> AnimationResult {
+    let id: egui::Id = id.into();
+
+    let result = animate_with_on_frame(ui, id, value, animation, add_contents, |_| {});
+    let current = match result.run_state.signed_progress() {
+        Some(position) => position,
+        None if result.just_finished => f32::INFINITY,
+        None => return result,
+    };
+    let previous = match result.just_started {
+        true => f32::NEG_INFINITY,
+        false => mem::get_mark(ui, id).unwrap_or(f32::NEG_INFINITY),
+    };
+
+    for (index, (phase, normal)) in marks.iter().enumerate() {
+        let position = phase.position(*normal);
+        if previous < position && position <= current {
+            on_mark(index);
+        }
+    }
+    match result.run_state.is_running() {
+        true => mem::insert_mark(ui, id, current),
+        false => _ = mem::clear_mark(ui, id),
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_ANIM: Animation = Animation::new(1.0, |_, _| {}, |_, _| {});
+    const MARKS: &[(Phase, f32)] = &[(Phase::Out, 0.0), (Phase::In, 0.5), (Phase::In, 1.0)];
+
+    /// Run a single frame at the given `time`, returning the fired marks.
+    fn run_frame(ctx: &egui::Context, time: f64, value: i32) -> Vec<usize> {
+        let mut fired = Vec::new();
+        let input = egui::RawInput {
+            time: Some(time),
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                animate_at(
+                    ui,
+                    "test_anim",
+                    value,
+                    TEST_ANIM,
+                    MARKS,
+                    |_, _| {},
+                    |index| fired.push(index),
+                );
+            });
+        });
+        fired
+    }
+
+    #[test]
+    fn test_animate_at() {
+        let ctx = egui::Context::default();
+
+        assert!(run_frame(&ctx, 0.0, 0).is_empty());
+        assert_eq!(run_frame(&ctx, 0.0, 1), vec![0]);
+        assert!(run_frame(&ctx, 0.5, 1).is_empty());
+        assert!(run_frame(&ctx, 0.7, 1).is_empty());
+        assert_eq!(run_frame(&ctx, 0.8, 1), vec![1]);
+        assert!(run_frame(&ctx, 0.9, 1).is_empty());
+        assert_eq!(run_frame(&ctx, 1.0, 1), vec![2]);
+        assert!(run_frame(&ctx, 1.5, 1).is_empty());
+
+        // Skipped marks fire on the frame the animation finishes.
+        assert_eq!(run_frame(&ctx, 2.0, 2), vec![0]);
+        assert_eq!(run_frame(&ctx, 4.0, 2), vec![1, 2]);
+    }
+}
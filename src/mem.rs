@@ -1,10 +1,46 @@
 //! Functions that interact with `egui` persistant memory.
 use std::any::Any;
 
-use crate::AnimationSegment;
+use crate::{Animation, AnimationClock, AnimationSegment, AnimationStats};
 
-const START_TIME_SUFFIX: &'static str = "start_time";
-const START_VALUE_SUFFIX: &'static str = "start_value";
+const START_TIME_SUFFIX: &str = "start_time";
+const START_VALUE_SUFFIX: &str = "start_value";
+const TARGET_VALUE_SUFFIX: &str = "target_value";
+const LATCH_SUFFIX: &str = "latch";
+const QUEUE_SUFFIX: &str = "queue";
+const CLOCK_SUFFIX: &str = "clock";
+const RETRIGGER_SUFFIX: &str = "retrigger";
+const RECT_SUFFIX: &str = "rect";
+const RESERVED_SIZE_SUFFIX: &str = "reserved_size";
+const MARK_SUFFIX: &str = "mark";
+const STATS_SUFFIX: &str = "stats";
+const OBSERVED_SUFFIX: &str = "observed";
+const DISCARDED_SUFFIX: &str = "discarded";
+const COUNT_SUFFIX: &str = "count";
+const SLIDE_SUFFIX: &str = "slide";
+const SHARED_TARGET_SUFFIX: &str = "shared_target";
+const FRAMES_SUFFIX: &str = "frames";
+const DEBUG_DRAW_ID: &str = "egui_animate_debug_draw";
+const ANIMATION_ID: &str = "egui_animate_animation_id";
+const REGISTRY_ID: &str = "egui_animate_registry";
+const DATA_ID: &str = "egui_animate_data";
+const ROTATION_ID: &str = "egui_animate_rotation";
+const FRAME_BUDGET_ID: &str = "egui_animate_frame_budget";
+const DEFAULT_ANIMATION_ID: &str = "egui_animate_default_animation";
+const DEFAULT_EASING_ID: &str = "egui_animate_default_easing";
+const MAX_FPS_ID: &str = "egui_animate_max_fps";
+const BACKGROUND_FPS_ID: &str = "egui_animate_background_fps";
+const SLIDE_FALLBACK_ID: &str = "egui_animate_slide_fallback";
+const HIDE_WHILE_ANIMATING_ID: &str = "egui_animate_hide_while_animating";
+const DISABLED_ID: &str = "egui_animate_disabled";
+#[cfg(debug_assertions)]
+const PASS_IDS_ID: &str = "egui_animate_pass_ids";
+
+/// Get the id keeping the start time and clock of the frame-driven animation of the given
+/// `id`, in frames rather than seconds.
+pub(super) fn frames_id(id: egui::Id) -> egui::Id {
+    id.with(FRAMES_SUFFIX)
+}
 
 pub(super) fn get_or_insert_start_time(ui: &mut egui::Ui, id: egui::Id, current_time: f64) -> f64 {
     ui.ctx().memory_mut(|m| {
@@ -13,6 +49,12 @@ pub(super) fn get_or_insert_start_time(ui: &mut egui::Ui, id: egui::Id, current_
     })
 }
 
+pub(super) fn insert_start_time(ui: &mut egui::Ui, id: egui::Id, start_time: f64) -> f64 {
+    ui.ctx()
+        .memory_mut(|m| m.data.insert_temp(id.with(START_TIME_SUFFIX), start_time));
+    start_time
+}
+
 pub(super) fn get_start_time(ui: &mut egui::Ui, id: egui::Id) -> Option<f64> {
     ui.ctx()
         .memory_mut(|m| m.data.get_temp(id.with(START_TIME_SUFFIX)))
@@ -23,6 +65,165 @@ pub(super) fn clear_start_time(ui: &mut egui::Ui, id: egui::Id) -> Option<f64> {
         .memory_mut(|m| m.data.remove_temp(id.with(START_TIME_SUFFIX)))
 }
 
+pub(super) fn get_or_insert_clock(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    clock: impl FnOnce() -> AnimationClock,
+) -> AnimationClock {
+    ui.ctx().memory_mut(|m| {
+        *m.data
+            .get_temp_mut_or_insert_with(id.with(CLOCK_SUFFIX), clock)
+    })
+}
+
+pub(super) fn insert_clock(ui: &mut egui::Ui, id: egui::Id, clock: AnimationClock) {
+    ui.ctx()
+        .memory_mut(|m| m.data.insert_temp(id.with(CLOCK_SUFFIX), clock));
+}
+
+pub(super) fn get_clock(ui: &mut egui::Ui, id: egui::Id) -> Option<AnimationClock> {
+    ui.ctx()
+        .memory_mut(|m| m.data.get_temp(id.with(CLOCK_SUFFIX)))
+}
+
+pub(super) fn clear_clock(ui: &mut egui::Ui, id: egui::Id) -> Option<AnimationClock> {
+    ui.ctx()
+        .memory_mut(|m| m.data.remove_temp(id.with(CLOCK_SUFFIX)))
+}
+
+pub(super) fn insert_retrigger(ui: &mut egui::Ui, id: egui::Id) {
+    ui.ctx()
+        .memory_mut(|m| m.data.insert_temp(id.with(RETRIGGER_SUFFIX), true));
+}
+
+pub(super) fn get_retrigger(ui: &mut egui::Ui, id: egui::Id) -> bool {
+    ui.ctx()
+        .memory_mut(|m| m.data.get_temp(id.with(RETRIGGER_SUFFIX)))
+        .unwrap_or_default()
+}
+
+pub(super) fn clear_retrigger(ui: &mut egui::Ui, id: egui::Id) -> Option<bool> {
+    ui.ctx()
+        .memory_mut(|m| m.data.remove_temp(id.with(RETRIGGER_SUFFIX)))
+}
+
+pub(super) fn get_rect(ui: &mut egui::Ui, id: egui::Id) -> Option<egui::Rect> {
+    ui.ctx()
+        .memory_mut(|m| m.data.get_temp(id.with(RECT_SUFFIX)))
+}
+
+pub(super) fn insert_rect(ui: &mut egui::Ui, id: egui::Id, rect: egui::Rect) {
+    ui.ctx()
+        .memory_mut(|m| m.data.insert_temp(id.with(RECT_SUFFIX), rect));
+}
+
+pub(super) fn clear_rect(ui: &mut egui::Ui, id: egui::Id) {
+    ui.ctx()
+        .memory_mut(|m| m.data.remove::<egui::Rect>(id.with(RECT_SUFFIX)));
+}
+
+pub(super) fn get_reserved_size(ui: &mut egui::Ui, id: egui::Id) -> Option<egui::Vec2> {
+    ui.ctx()
+        .memory_mut(|m| m.data.get_temp(id.with(RESERVED_SIZE_SUFFIX)))
+}
+
+pub(super) fn insert_reserved_size(ui: &mut egui::Ui, id: egui::Id, size: egui::Vec2) {
+    ui.ctx()
+        .memory_mut(|m| m.data.insert_temp(id.with(RESERVED_SIZE_SUFFIX), size));
+}
+
+pub(super) fn clear_reserved_size(ui: &mut egui::Ui, id: egui::Id) {
+    ui.ctx()
+        .memory_mut(|m| m.data.remove::<egui::Vec2>(id.with(RESERVED_SIZE_SUFFIX)));
+}
+
+pub(super) fn get_shared_target<T: 'static + Any + Clone + Send + Sync>(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+) -> Option<T> {
+    ui.ctx()
+        .memory_mut(|m| m.data.get_temp(id.with(SHARED_TARGET_SUFFIX)))
+}
+
+pub(super) fn insert_shared_target<T: 'static + Any + Clone + Send + Sync>(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    value: T,
+) -> T {
+    ui.ctx().memory_mut(|m| {
+        m.data
+            .insert_temp(id.with(SHARED_TARGET_SUFFIX), value.clone())
+    });
+    value
+}
+
+pub(super) fn get_mark(ui: &mut egui::Ui, id: egui::Id) -> Option<f32> {
+    ui.ctx()
+        .memory_mut(|m| m.data.get_temp(id.with(MARK_SUFFIX)))
+}
+
+pub(super) fn insert_mark(ui: &mut egui::Ui, id: egui::Id, position: f32) {
+    ui.ctx()
+        .memory_mut(|m| m.data.insert_temp(id.with(MARK_SUFFIX), position));
+}
+
+pub(super) fn clear_mark(ui: &mut egui::Ui, id: egui::Id) -> Option<f32> {
+    ui.ctx()
+        .memory_mut(|m| m.data.remove_temp(id.with(MARK_SUFFIX)))
+}
+
+pub(super) fn get_stats(ui: &egui::Ui, id: egui::Id) -> Option<AnimationStats> {
+    ui.ctx().memory(|m| m.data.get_temp(id.with(STATS_SUFFIX)))
+}
+
+pub(super) fn clear_stats(ui: &mut egui::Ui, id: egui::Id) {
+    ui.ctx()
+        .memory_mut(|m| m.data.remove::<AnimationStats>(id.with(STATS_SUFFIX)));
+}
+
+pub(super) fn update_stats(ui: &mut egui::Ui, id: egui::Id, f: impl FnOnce(&mut AnimationStats)) {
+    ui.ctx().memory_mut(|m| {
+        f(m.data
+            .get_temp_mut_or_default::<AnimationStats>(id.with(STATS_SUFFIX)));
+    });
+}
+
+/// Record whether the animation of the given `id` is `running` on the current pass.
+/// Returns `true` if the animation was running on the prior observed pass.
+///
+/// The observation belongs to the observer rather than the animation, and is kept once
+/// the animation finishes or is settled, so that the completion is still observed.
+pub(super) fn observe_running(ui: &mut egui::Ui, id: egui::Id, running: bool) -> bool {
+    let pass_nr = ui.ctx().cumulative_pass_nr();
+    ui.ctx().memory_mut(|m| {
+        // The pass number, and whether the animation was running on the prior and
+        // current passes.
+        let observed = m
+            .data
+            .get_temp_mut_or_insert_with(id.with(OBSERVED_SUFFIX), || (pass_nr, false, running));
+        if observed.0 != pass_nr {
+            *observed = (pass_nr, observed.2, running);
+        }
+        observed.2 = running;
+        observed.1
+    })
+}
+
+/// Mark the contents of the given `id` as discarded for measuring. Returns `true` if a
+/// pass was already discarded for the unmeasured contents.
+pub(super) fn insert_discarded(ctx: &egui::Context, id: egui::Id) -> bool {
+    ctx.memory_mut(|m| {
+        let discarded = m
+            .data
+            .get_temp_mut_or_default::<bool>(id.with(DISCARDED_SUFFIX));
+        std::mem::replace(discarded, true)
+    })
+}
+
+pub(super) fn clear_discarded(ctx: &egui::Context, id: egui::Id) {
+    ctx.memory_mut(|m| m.data.remove::<bool>(id.with(DISCARDED_SUFFIX)));
+}
+
 pub(super) fn get_or_insert_start_value<T: 'static + Any + Clone + Send + Sync>(
     ui: &mut egui::Ui,
     id: egui::Id,
@@ -35,6 +236,23 @@ pub(super) fn get_or_insert_start_value<T: 'static + Any + Clone + Send + Sync>(
     })
 }
 
+pub(super) fn insert_start_value<T: 'static + Any + Clone + Send + Sync>(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    start_value: T,
+) {
+    ui.ctx()
+        .memory_mut(|m| m.data.insert_temp(id.with(START_VALUE_SUFFIX), start_value));
+}
+
+pub(super) fn get_start_value<T: 'static + Any + Clone + Send + Sync>(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+) -> Option<T> {
+    ui.ctx()
+        .memory_mut(|m| m.data.get_temp(id.with(START_VALUE_SUFFIX)))
+}
+
 pub(super) fn clear_start_value<T: 'static + Any + Clone + Send + Sync + Default>(
     ui: &mut egui::Ui,
     id: egui::Id,
@@ -43,6 +261,101 @@ pub(super) fn clear_start_value<T: 'static + Any + Clone + Send + Sync + Default
         .memory_mut(|m| m.data.remove_temp(id.with(START_VALUE_SUFFIX)))
 }
 
+pub(super) fn insert_target_value<T: 'static + Any + Clone + Send + Sync>(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    target_value: T,
+) {
+    ui.ctx().memory_mut(|m| {
+        m.data
+            .insert_temp(id.with(TARGET_VALUE_SUFFIX), target_value)
+    });
+}
+
+pub(super) fn get_target_value<T: 'static + Any + Clone + Send + Sync>(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+) -> Option<T> {
+    ui.ctx()
+        .memory_mut(|m| m.data.get_temp(id.with(TARGET_VALUE_SUFFIX)))
+}
+
+pub(super) fn clear_target_value<T: 'static + Any + Clone + Send + Sync + Default>(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+) -> Option<T> {
+    ui.ctx()
+        .memory_mut(|m| m.data.remove_temp(id.with(TARGET_VALUE_SUFFIX)))
+}
+
+/// The `(from, to)` values of a count, and its start time. The start time is `None`
+/// while idle.
+pub(super) type Count = (f64, f64, Option<f64>);
+
+pub(super) fn get_or_insert_count(ui: &mut egui::Ui, id: egui::Id, value: f64) -> Count {
+    ui.ctx().memory_mut(|m| {
+        *m.data
+            .get_temp_mut_or_insert_with(id.with(COUNT_SUFFIX), || (value, value, None))
+    })
+}
+
+pub(super) fn insert_count(ui: &mut egui::Ui, id: egui::Id, count: Count) {
+    ui.ctx()
+        .memory_mut(|m| m.data.insert_temp(id.with(COUNT_SUFFIX), count));
+}
+
+/// The `(from, to)` rects of a slide, and its start time. The start time is `None`
+/// while idle.
+pub(super) type Slide = (egui::Rect, egui::Rect, Option<f64>);
+
+pub(super) fn get_or_insert_slide(ui: &mut egui::Ui, id: egui::Id, target: egui::Rect) -> Slide {
+    ui.ctx().memory_mut(|m| {
+        *m.data
+            .get_temp_mut_or_insert_with(id.with(SLIDE_SUFFIX), || (target, target, None))
+    })
+}
+
+pub(super) fn insert_slide(ui: &mut egui::Ui, id: egui::Id, slide: Slide) {
+    ui.ctx()
+        .memory_mut(|m| m.data.insert_temp(id.with(SLIDE_SUFFIX), slide));
+}
+
+pub(super) fn toggle_latch(ui: &mut egui::Ui, id: egui::Id, toggle: bool) -> bool {
+    ui.ctx().memory_mut(|m| {
+        let latch = m
+            .data
+            .get_temp_mut_or_default::<bool>(id.with(LATCH_SUFFIX));
+        *latch ^= toggle;
+        *latch
+    })
+}
+
+pub(super) fn get_or_insert_queue<Q: 'static + Any + Clone + Send + Sync>(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    queue: impl FnOnce() -> Q,
+) -> Q {
+    ui.ctx().memory_mut(|m| {
+        m.data
+            .get_temp_mut_or_insert_with(id.with(QUEUE_SUFFIX), queue)
+            .clone()
+    })
+}
+
+pub(super) fn insert_queue<Q: 'static + Any + Clone + Send + Sync>(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    queue: Q,
+) {
+    ui.ctx()
+        .memory_mut(|m| m.data.insert_temp(id.with(QUEUE_SUFFIX), queue));
+}
+
+pub(super) fn clear_queue<Q: 'static + Any + Clone + Send + Sync>(ui: &mut egui::Ui, id: egui::Id) {
+    ui.ctx()
+        .memory_mut(|m| m.data.remove::<Q>(id.with(QUEUE_SUFFIX)));
+}
+
 pub(super) fn clear_animation_layer(
     ui: &mut egui::Ui,
     id: egui::Id,
@@ -50,3 +363,290 @@ pub(super) fn clear_animation_layer(
     let layer_id = AnimationSegment::animation_layer(ui, id);
     ui.memory_mut(|m| m.to_global.remove(&layer_id))
 }
+
+pub(super) fn get_debug_draw(ctx: &egui::Context) -> bool {
+    ctx.memory(|m| m.data.get_temp(egui::Id::new(DEBUG_DRAW_ID)))
+        .unwrap_or_default()
+}
+
+pub(super) fn set_debug_draw(ctx: &egui::Context, enabled: bool) {
+    ctx.memory_mut(|m| m.data.insert_temp(egui::Id::new(DEBUG_DRAW_ID), enabled));
+}
+
+pub(super) fn get_frame_budget(ctx: &egui::Context) -> Option<f32> {
+    ctx.memory(|m| m.data.get_temp(egui::Id::new(FRAME_BUDGET_ID)))
+        .flatten()
+}
+
+pub(super) fn set_frame_budget(ctx: &egui::Context, budget: Option<f32>) {
+    ctx.memory_mut(|m| m.data.insert_temp(egui::Id::new(FRAME_BUDGET_ID), budget));
+}
+
+pub(super) fn get_max_fps(ctx: &egui::Context) -> Option<f32> {
+    ctx.memory(|m| m.data.get_temp(egui::Id::new(MAX_FPS_ID)))
+        .flatten()
+}
+
+pub(super) fn set_max_fps(ctx: &egui::Context, max_fps: Option<f32>) {
+    ctx.memory_mut(|m| m.data.insert_temp(egui::Id::new(MAX_FPS_ID), max_fps));
+}
+
+pub(super) fn get_background_fps(ctx: &egui::Context) -> Option<f32> {
+    ctx.memory(|m| m.data.get_temp(egui::Id::new(BACKGROUND_FPS_ID)))
+        .flatten()
+}
+
+pub(super) fn set_background_fps(ctx: &egui::Context, background_fps: Option<f32>) {
+    ctx.memory_mut(|m| {
+        m.data
+            .insert_temp(egui::Id::new(BACKGROUND_FPS_ID), background_fps)
+    });
+}
+
+pub(super) fn get_hide_while_animating(ctx: &egui::Context) -> bool {
+    ctx.memory(|m| m.data.get_temp(egui::Id::new(HIDE_WHILE_ANIMATING_ID)))
+        .unwrap_or_default()
+}
+
+pub(super) fn set_hide_while_animating(ctx: &egui::Context, enabled: bool) {
+    ctx.memory_mut(|m| {
+        m.data
+            .insert_temp(egui::Id::new(HIDE_WHILE_ANIMATING_ID), enabled)
+    });
+}
+
+pub(super) fn get_slide_fallback(ctx: &egui::Context) -> bool {
+    ctx.memory(|m| m.data.get_temp(egui::Id::new(SLIDE_FALLBACK_ID)))
+        .unwrap_or_default()
+}
+
+pub(super) fn set_slide_fallback(ctx: &egui::Context, enabled: bool) {
+    ctx.memory_mut(|m| {
+        m.data
+            .insert_temp(egui::Id::new(SLIDE_FALLBACK_ID), enabled)
+    });
+}
+
+pub(super) fn get_animation_disabled(ctx: &egui::Context, id: egui::Id) -> bool {
+    ctx.memory(|m| {
+        m.data
+            .get_temp::<std::collections::HashSet<egui::Id>>(egui::Id::new(DISABLED_ID))
+            .is_some_and(|disabled| disabled.contains(&id))
+    })
+}
+
+pub(super) fn set_animation_disabled(ctx: &egui::Context, id: egui::Id, disabled: bool) {
+    ctx.memory_mut(|m| {
+        let ids = m
+            .data
+            .get_temp_mut_or_default::<std::collections::HashSet<egui::Id>>(egui::Id::new(
+                DISABLED_ID,
+            ));
+        match disabled {
+            true => ids.insert(id),
+            false => ids.remove(&id),
+        };
+    });
+}
+
+pub(super) fn get_default_easing(ctx: &egui::Context) -> Option<fn(f32) -> f32> {
+    ctx.memory(|m| m.data.get_temp(egui::Id::new(DEFAULT_EASING_ID)))
+}
+
+pub(super) fn set_default_easing(ctx: &egui::Context, easing: fn(f32) -> f32) {
+    ctx.memory_mut(|m| m.data.insert_temp(egui::Id::new(DEFAULT_EASING_ID), easing));
+}
+
+pub(super) fn get_default_animation(ctx: &egui::Context) -> Option<Animation> {
+    ctx.memory(|m| m.data.get_temp(egui::Id::new(DEFAULT_ANIMATION_ID)))
+}
+
+pub(super) fn set_default_animation(ctx: &egui::Context, animation: Animation) {
+    ctx.memory_mut(|m| {
+        m.data
+            .insert_temp(egui::Id::new(DEFAULT_ANIMATION_ID), animation)
+    });
+}
+
+pub(super) fn get_animation_id(ctx: &egui::Context) -> Option<egui::Id> {
+    ctx.memory(|m| m.data.get_temp(egui::Id::new(ANIMATION_ID)))
+}
+
+/// Call `f` with the given `id` as the animation currently being applied, restoring the
+/// prior animation id (e.g. of an enclosing animation) afterwards.
+pub(super) fn with_animation_id<R>(ctx: &egui::Context, id: egui::Id, f: impl FnOnce() -> R) -> R {
+    let key = egui::Id::new(ANIMATION_ID);
+    let prior = ctx.memory_mut(|m| {
+        let prior = m.data.get_temp::<egui::Id>(key);
+        m.data.insert_temp(key, id);
+        prior
+    });
+    let inner = f();
+    ctx.memory_mut(|m| match prior {
+        Some(prior) => m.data.insert_temp(key, prior),
+        None => m.data.remove::<egui::Id>(key),
+    });
+    inner
+}
+
+/// Call `f` with the data of type `D` of the given animation `id`, inserting the
+/// default if absent. The data of each animation is kept in a separate `IdTypeMap`, so
+/// that it can be cleared without knowing the type.
+pub(super) fn with_data<D: 'static + Any + Clone + Send + Sync + Default, R>(
+    ctx: &egui::Context,
+    id: egui::Id,
+    f: impl FnOnce(&mut D) -> R,
+) -> R {
+    ctx.memory_mut(|m| {
+        let data = m
+            .data
+            .get_temp_mut_or_default::<egui::IdMap<egui::util::IdTypeMap>>(egui::Id::new(DATA_ID))
+            .entry(id)
+            .or_default();
+        f(data.get_temp_mut_or_default::<D>(egui::Id::NULL))
+    })
+}
+
+pub(super) fn clear_data(ctx: &egui::Context, id: egui::Id) {
+    ctx.memory_mut(|m| {
+        m.data
+            .get_temp_mut_or_default::<egui::IdMap<egui::util::IdTypeMap>>(egui::Id::new(DATA_ID))
+            .remove(&id);
+    });
+}
+
+/// A rotation of the shapes of a layer, about an origin.
+type LayerRotation = (egui::LayerId, egui::emath::Rot2, egui::Pos2);
+
+/// Set the rotation of the shapes of the given `layer_id`, replacing any prior rotation
+/// on the current pass.
+pub(super) fn insert_rotation(
+    ctx: &egui::Context,
+    layer_id: egui::LayerId,
+    rot: egui::emath::Rot2,
+    origin: egui::Pos2,
+) {
+    ctx.memory_mut(|m| {
+        let rotations = m
+            .data
+            .get_temp_mut_or_default::<Vec<LayerRotation>>(egui::Id::new(ROTATION_ID));
+        rotations.retain(|(other_id, ..)| *other_id != layer_id);
+        rotations.push((layer_id, rot, origin));
+    });
+}
+
+/// Take the layer rotations of the current pass.
+pub(super) fn take_rotations(ctx: &egui::Context) -> Vec<LayerRotation> {
+    ctx.memory_mut(|m| m.data.remove_temp(egui::Id::new(ROTATION_ID)))
+        .unwrap_or_default()
+}
+
+/// Mark the given animation `id` as animated on the current pass. Returns `true` if the
+/// `id` was already animated on the current pass.
+#[cfg(debug_assertions)]
+pub(super) fn mark_animated(ctx: &egui::Context, id: egui::Id) -> bool {
+    let current_pass_nr = ctx.cumulative_pass_nr();
+    ctx.memory_mut(|m| {
+        let (pass_nr, ids) = m
+            .data
+            .get_temp_mut_or_default::<(u64, std::collections::HashSet<egui::Id>)>(egui::Id::new(
+                PASS_IDS_ID,
+            ));
+        if *pass_nr != current_pass_nr {
+            *pass_nr = current_pass_nr;
+            ids.clear();
+        }
+        !ids.insert(id)
+    })
+}
+
+/// A registered animation, and the pass it was last updated on.
+#[derive(Clone, Copy)]
+struct Registration {
+    pass_nr: u64,
+    /// Clears the typed start and target values, unknown once the animation is no longer
+    /// updated.
+    clear_values: fn(&mut egui::util::IdTypeMap, egui::Id),
+}
+
+pub(super) fn register_animation<T: 'static + Any + Clone + Send + Sync + Default>(
+    ctx: &egui::Context,
+    id: egui::Id,
+) {
+    let registration = Registration {
+        pass_nr: ctx.cumulative_pass_nr(),
+        clear_values: |data, id| {
+            data.remove::<T>(id.with(START_VALUE_SUFFIX));
+            data.remove::<T>(id.with(TARGET_VALUE_SUFFIX));
+        },
+    };
+    ctx.memory_mut(|m| {
+        m.data
+            .get_temp_mut_or_default::<egui::IdMap<Registration>>(egui::Id::new(REGISTRY_ID))
+            .insert(id, registration);
+    });
+}
+
+pub(super) fn unregister_animation(ctx: &egui::Context, id: egui::Id) {
+    ctx.memory_mut(|m| {
+        m.data
+            .get_temp_mut_or_default::<egui::IdMap<Registration>>(egui::Id::new(REGISTRY_ID))
+            .remove(&id);
+    });
+}
+
+/// Get the ids of all registered animations.
+pub(super) fn registered_animations(ctx: &egui::Context) -> Vec<egui::Id> {
+    ctx.memory(|m| {
+        m.data
+            .get_temp::<egui::IdMap<Registration>>(egui::Id::new(REGISTRY_ID))
+            .map(|registry| registry.keys().copied().collect())
+            .unwrap_or_default()
+    })
+}
+
+/// Unregister animations last updated before the given `pass_nr`, clearing their start
+/// time, clock, values, retrigger, rect, reserved size, discard flag and data. Returns
+/// the settled ids.
+pub(super) fn settle_animations(ctx: &egui::Context, pass_nr: u64) -> Vec<egui::Id> {
+    ctx.memory_mut(|m| {
+        let registry = m
+            .data
+            .get_temp_mut_or_default::<egui::IdMap<Registration>>(egui::Id::new(REGISTRY_ID));
+        let settled: Vec<_> = registry
+            .iter()
+            .filter(|(_, registration)| registration.pass_nr < pass_nr)
+            .map(|(id, registration)| (*id, registration.clear_values))
+            .collect();
+        for (id, _) in &settled {
+            registry.remove(id);
+        }
+
+        for (id, clear_values) in &settled {
+            clear_values(&mut m.data, *id);
+            m.data.remove::<f64>(id.with(START_TIME_SUFFIX));
+            m.data.remove::<AnimationClock>(id.with(CLOCK_SUFFIX));
+            m.data.remove::<f64>(frames_id(*id).with(START_TIME_SUFFIX));
+            m.data
+                .remove::<AnimationClock>(frames_id(*id).with(CLOCK_SUFFIX));
+            m.data.remove::<bool>(id.with(RETRIGGER_SUFFIX));
+            m.data.remove::<egui::Rect>(id.with(RECT_SUFFIX));
+            m.data.remove::<egui::Vec2>(id.with(RESERVED_SIZE_SUFFIX));
+            m.data.remove::<bool>(id.with(DISCARDED_SUFFIX));
+            m.data.remove::<f32>(id.with(MARK_SUFFIX));
+            m.data.remove::<AnimationStats>(id.with(STATS_SUFFIX));
+        }
+        let data = m
+            .data
+            .get_temp_mut_or_default::<egui::IdMap<egui::util::IdTypeMap>>(egui::Id::new(DATA_ID));
+        for (id, _) in &settled {
+            data.remove(id);
+        }
+        settled.into_iter().map(|(id, _)| id).collect()
+    })
+}
+
+/// Clear the transforms of all animation layers of the given `id`, regardless of order.
+pub(super) fn clear_animation_layers(ctx: &egui::Context, id: egui::Id) {
+    ctx.memory_mut(|m| m.to_global.retain(|layer_id, _| layer_id.id != id));
+}
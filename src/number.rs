@@ -0,0 +1,181 @@
+//! Animated numeric labels.
+use crate::enabled::animation_enabled;
+use crate::fps::{frame_interval, frame_time, request_repaint_after};
+use crate::mem;
+
+/// Show a label of the given `value`, counting from the prior value to the new value
+/// over the given `duration` (in seconds) on change. Each intermediate value is
+/// formatted with `format`.
+///
+/// Changing the value mid-count continues counting from the currently displayed value.
+/// Disabled animations (see [`set_animation_enabled`](crate::set_animation_enabled)) show
+/// the new value immediately, as do non-finite values.
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// let mut score: u32 = 0;
+///
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// if ui.button("Score").clicked() {
+///     score += 100;
+/// }
+/// animate_number(ui, "score", score as f64, 0.5, |value| format!("{value:.0}"));
+/// #
+/// # });
+/// # });
+/// ```
+pub fn animate_number(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    value: f64,
+    duration: f32,
+    format: impl Fn(f64) -> String,
+) -> egui::Response {
+    let id: egui::Id = id.into();
+    let ctx = ui.ctx().clone();
+    let current_time = ctx.input(|input| input.time);
+    let enabled = animation_enabled(&ctx, id);
+
+    // The value displayed for the given count, snapping to the target without duration.
+    let displayed = |(from, to, start_time): mem::Count| match start_time {
+        Some(start_time) if enabled && duration > 0.0 => {
+            let t = (frame_time(&ctx, start_time, current_time) - start_time) / duration as f64;
+            egui::lerp(from..=to, t.clamp(0.0, 1.0))
+        }
+        _ => to,
+    };
+
+    let mut count = mem::get_or_insert_count(ui, id, value);
+    if count.1 != value {
+        // Non-finite values cannot be counted from or to, so they are shown at once.
+        let from = displayed(count);
+        count = match from.is_finite() && value.is_finite() {
+            true => (from, value, Some(current_time)),
+            false => (value, value, None),
+        };
+    }
+    let displayed = displayed(count);
+    match count.2.is_none() || displayed == value {
+        true => count = (value, value, None),
+        false => request_repaint_after(&ctx, frame_interval(&ctx)),
+    }
+    mem::insert_count(ui, id, count);
+
+    ui.label(format(displayed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run a single frame at the given `time`, returning the displayed value.
+    fn run_frame(ctx: &egui::Context, time: f64, value: f64, duration: f32) -> f64 {
+        let displayed = std::cell::Cell::new(f64::NAN);
+        let input = egui::RawInput {
+            time: Some(time),
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                animate_number(ui, "test_number", value, duration, |value| {
+                    displayed.set(value);
+                    format!("{value}")
+                });
+            });
+        });
+        displayed.get()
+    }
+
+    #[test]
+    fn test_animate_number() {
+        let ctx = egui::Context::default();
+
+        assert_eq!(run_frame(&ctx, 0.0, 0.0, 1.0), 0.0);
+        assert_eq!(run_frame(&ctx, 1.0, 100.0, 1.0), 0.0);
+        assert_eq!(run_frame(&ctx, 1.5, 100.0, 1.0), 50.0);
+        // Retarget mid-count from the displayed value.
+        assert_eq!(run_frame(&ctx, 1.5, 0.0, 1.0), 50.0);
+        assert_eq!(run_frame(&ctx, 2.0, 0.0, 1.0), 25.0);
+        assert_eq!(run_frame(&ctx, 2.5, 0.0, 1.0), 0.0);
+        assert_eq!(run_frame(&ctx, 3.0, 0.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_max_fps() {
+        let ctx = egui::Context::default();
+        crate::set_max_fps(&ctx, Some(4.0));
+
+        assert_eq!(run_frame(&ctx, 0.0, 0.0, 1.0), 0.0);
+        assert_eq!(run_frame(&ctx, 1.0, 100.0, 1.0), 0.0);
+        assert_eq!(run_frame(&ctx, 1.2, 100.0, 1.0), 0.0);
+        assert_eq!(run_frame(&ctx, 1.3, 100.0, 1.0), 25.0);
+        assert_eq!(run_frame(&ctx, 1.6, 100.0, 1.0), 50.0);
+        assert_eq!(run_frame(&ctx, 2.1, 100.0, 1.0), 100.0);
+    }
+
+    #[test]
+    fn test_disabled() {
+        let ctx = egui::Context::default();
+
+        assert_eq!(run_frame(&ctx, 0.0, 0.0, 1.0), 0.0);
+        assert_eq!(run_frame(&ctx, 1.0, 100.0, 1.0), 0.0);
+        // Disabling mid-count shows the target value.
+        crate::set_animation_enabled(&ctx, "test_number", false);
+        assert_eq!(run_frame(&ctx, 1.5, 100.0, 1.0), 100.0);
+        assert_eq!(run_frame(&ctx, 2.0, 0.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_zero_duration() {
+        let ctx = egui::Context::default();
+
+        assert_eq!(run_frame(&ctx, 0.0, 0.0, 0.0), 0.0);
+        assert_eq!(run_frame(&ctx, 1.0, 100.0, 0.0), 100.0);
+        assert_eq!(run_frame(&ctx, 1.0, 50.0, 0.0), 50.0);
+    }
+
+    #[test]
+    fn test_non_finite() {
+        let ctx = egui::Context::default();
+
+        assert_eq!(run_frame(&ctx, 0.0, 0.0, 1.0), 0.0);
+        assert!(run_frame(&ctx, 1.0, f64::NAN, 1.0).is_nan());
+        assert!(run_frame(&ctx, 1.5, f64::NAN, 1.0).is_nan());
+        assert!(!ctx.has_requested_repaint());
+        assert_eq!(run_frame(&ctx, 2.0, f64::INFINITY, 1.0), f64::INFINITY);
+        assert!(!ctx.has_requested_repaint());
+        // Counting from a non-finite value shows the new value at once.
+        assert_eq!(run_frame(&ctx, 2.5, 100.0, 1.0), 100.0);
+        assert!(!ctx.has_requested_repaint());
+    }
+
+    #[test]
+    fn test_shared_id() {
+        const TEST_ANIM: crate::Animation = crate::Animation::new(1.0, |_, _| {}, |_, _| {});
+        let ctx = egui::Context::default();
+        let id = egui::Id::new("test_number");
+        let run_frame = |value: i32| {
+            let mut running = false;
+            let _ = ctx.run(Default::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    crate::animate(ui, id, value, TEST_ANIM, |_, _| {});
+                    animate_number(ui, id, 1.0, 1.0, |value| format!("{value}"));
+                    running = crate::run_state(ui, id, TEST_ANIM).is_running();
+                });
+            });
+            running
+        };
+
+        assert!(!run_frame(0));
+        // The count of the same id does not clear the running animation.
+        assert!(run_frame(1));
+        assert!(run_frame(1));
+    }
+}
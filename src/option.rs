@@ -0,0 +1,107 @@
+//! Animations of optional values, entering and leaving with the value.
+use std::any::Any;
+
+use crate::state::animate_with;
+use crate::{Animation, AnimationResult, AnimationSegment};
+
+/// Create an animation of an optional `value`, playing the *in* segment when a value
+/// appears and the *out* segment when it is removed. See [`animate`](crate::animate).
+///
+/// The contents are rendered only for `Some` values. On removal, the last value is kept
+/// in memory and rendered through the *out* segment, then nothing is rendered. Changes
+/// between two `Some` values play the full animation.
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # const FADE_ANIM: Animation = Animation::EMPTY;
+/// let notification: Option<String> = Some("Saved".to_string());
+///
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// animate_option(ui, "notification", notification.clone(), FADE_ANIM, |ui, text| {
+///     ui.label(text);
+/// });
+/// #
+/// # });
+/// # });
+/// ```
+pub fn animate_option<T: 'static + Any + Clone + Send + Sync + PartialEq, R>(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    value: Option<T>,
+    animation: impl Into<Animation>,
+    add_contents: impl FnOnce(&mut egui::Ui, &T) -> R,
+) -> AnimationResult {
+    let animation = animation.into();
+    animate_with(
+        ui,
+        id.into(),
+        value,
+        |from, to| match (from, to) {
+            (None, Some(_)) => Animation::from_segments(AnimationSegment::EMPTY, animation.in_seg),
+            (Some(_), None) => Animation::from_segments(animation.out_seg, AnimationSegment::EMPTY),
+            _ => animation,
+        },
+        |ui, value| {
+            if let Some(value) = &value {
+                add_contents(ui, value);
+            }
+        },
+        |_| {},
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RunState;
+
+    const TEST_ANIM: Animation = Animation::new(1.0, |_, _| {}, |_, _| {});
+
+    /// Run a single frame at the given `time`, returning the result and rendered values.
+    fn run_frame(ctx: &egui::Context, time: f64, value: Option<i32>) -> (RunState, Vec<i32>) {
+        let mut shown = Vec::new();
+        let mut run_state = RunState::None;
+        let input = egui::RawInput {
+            time: Some(time),
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                run_state = animate_option(ui, "test_anim", value, TEST_ANIM, |_, value| {
+                    shown.push(*value);
+                })
+                .run_state;
+            });
+        });
+        (run_state, shown)
+    }
+
+    #[test]
+    fn test_animate_option() {
+        let ctx = egui::Context::default();
+
+        assert_eq!(run_frame(&ctx, 0.0, None), (RunState::None, vec![]));
+        // Entering plays only the in segment.
+        assert_eq!(
+            run_frame(&ctx, 1.0, Some(1)),
+            (RunState::InSeg(0.0), vec![1])
+        );
+        assert_eq!(run_frame(&ctx, 1.5, Some(1)), (RunState::None, vec![1]));
+        run_frame(&ctx, 2.0, Some(1));
+
+        // Leaving keeps the last value through the out segment.
+        assert_eq!(run_frame(&ctx, 3.0, None), (RunState::OutSeg(0.0), vec![1]));
+        assert_eq!(
+            run_frame(&ctx, 3.25, None),
+            (RunState::OutSeg(0.5), vec![1])
+        );
+        assert_eq!(run_frame(&ctx, 3.5, None), (RunState::None, vec![]));
+    }
+}
@@ -0,0 +1,16 @@
+//! Common imports for typical use: [`animate`], [`Animation`], [`RunState`], the macros,
+//! the [`presets`] and the [`easing`] utilities. Other items are imported from the crate
+//! root.
+//!
+//! ```
+//! use egui_animate::prelude::*;
+//!
+//! const FADE: Animation = Animation::new(
+//!     0.3,
+//!     |ui, normal| ui.set_opacity(1.0 - normal),
+//!     |ui, normal| ui.set_opacity(normal),
+//! );
+//! ```
+pub use crate::{Animation, RunState, animate};
+pub use crate::{anim_id, animation, combine, cycle, reverse};
+pub use crate::{easing, presets};
@@ -0,0 +1,937 @@
+//! Common animation functions, for use within `fn(&mut egui::Ui, f32)` definitions, and
+//! common animations.
+//!
+//! Preset functions pass the *normal* through unchanged. Ease them at the segment level,
+//! such as with [`AnimationSegment::with_default_easing`](crate::AnimationSegment::with_default_easing).
+use egui::emath::easing::{quadratic_in_out, quadratic_out};
+use egui::emath::{Rot2, TSTransform};
+use egui::epaint::Tessellator;
+use egui::{Pos2, Shape, Vec2};
+
+use crate::{Animation, mem, slide_fallback};
+
+/// Translate the animation layer by the given `offset`, written for left-to-right
+/// layouts. The horizontal offset is flipped for right-to-left layouts (see
+/// [`egui::Layout::prefer_right_to_left`]), so "forward" slides follow the reading
+/// direction.
+///
+/// With [`crate::set_slide_fallback`] enabled, layout space is consumed instead of
+/// transforming the layer. Only the offset along the main direction of the layout is
+/// applied, and the cross axis offset is dropped. Negative offsets pull the contents back
+/// over the widgets before them, as with a negative [`egui::Ui::add_space`].
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// // Slide forward, entering from the end of the reading direction.
+/// const SLIDE_IN: Animation = Animation::new_in(0.3, |ui, normal| {
+///     presets::slide(ui, egui::vec2((1.0 - normal) * 10.0, 0.0));
+/// });
+/// ```
+pub fn slide(ui: &mut egui::Ui, offset: Vec2) {
+    let offset = reading_offset(ui, offset);
+    translate(ui, offset);
+}
+
+/// Fade the contents in, sliding from the given `offset` to rest, for the given
+/// `normal`. See [`slide`].
+///
+/// While sliding, painting is clipped to the rect of the contents at rest, measured on
+/// the prior pass, so the contents never overlap neighboring widgets. The clip composes
+/// with the existing clip rect, as with [`clip_width`]. The slide fallback applies as
+/// with [`slide`].
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// const SLIDE_FADE: Animation = Animation::new(
+///     0.3,
+///     |ui, normal| presets::slide_fade(ui, 1.0 - normal, egui::vec2(-10.0, 0.0)),
+///     |ui, normal| presets::slide_fade(ui, normal, egui::vec2(10.0, 0.0)),
+/// );
+/// ```
+pub fn slide_fade(ui: &mut egui::Ui, normal: f32, offset: Vec2) {
+    let offset = reading_offset(ui, offset) * (1.0 - normal);
+
+    if normal < 1.0 {
+        // The clip rect is transformed with the layer, so it is offset to stay at rest.
+        let clip_rect = ui.clip_rect().intersect(content_rect(ui));
+        match slide_fallback(ui.ctx()) {
+            true => ui.set_clip_rect(clip_rect),
+            false => ui.set_clip_rect(clip_rect.translate(-offset)),
+        }
+    }
+    translate(ui, offset);
+    ui.multiply_opacity(normal);
+}
+
+/// Translate the contents by the given `offset`, with a layer transform, or with
+/// layout space along the main direction if the slide fallback is enabled. See
+/// [`crate::set_slide_fallback`].
+fn translate(ui: &mut egui::Ui, offset: Vec2) {
+    if !slide_fallback(ui.ctx()) {
+        ui.ctx()
+            .set_transform_layer(ui.layer_id(), TSTransform::from_translation(offset));
+        return;
+    }
+    let amount = match ui.layout().main_dir() {
+        egui::Direction::LeftToRight => offset.x,
+        egui::Direction::RightToLeft => -offset.x,
+        egui::Direction::TopDown => offset.y,
+        egui::Direction::BottomUp => -offset.y,
+    };
+    if amount != 0.0 {
+        ui.add_space(amount);
+    }
+}
+
+/// Flip the horizontal `offset` for right-to-left layouts.
+fn reading_offset(ui: &egui::Ui, offset: Vec2) -> Vec2 {
+    match ui.layout().prefer_right_to_left() {
+        true => Vec2::new(-offset.x, offset.y),
+        false => offset,
+    }
+}
+
+/// Translate the animation layer along a cubic Bézier curve for the given `normal`.
+/// The layer is offset by the first control point at `0.0`, and the last at `1.0`.
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// # use egui::vec2;
+/// // Fly in from the top left corner along a curve.
+/// const CURVE: [egui::Vec2; 4] = [vec2(-40.0, -40.0), vec2(-40.0, 0.0), vec2(-10.0, 0.0), vec2(0.0, 0.0)];
+///
+/// const FLY_IN: Animation = Animation::new_in(0.4, |ui, normal| {
+///     presets::bezier_slide(ui, normal, CURVE);
+/// });
+/// ```
+pub fn bezier_slide(ui: &mut egui::Ui, normal: f32, control_points: [Vec2; 4]) {
+    let translation = cubic_bezier(control_points, normal);
+    ui.ctx()
+        .set_transform_layer(ui.layer_id(), TSTransform::from_translation(translation));
+}
+
+/// Scale the animation layer by the given `scale` factor, about the center of the
+/// contents. The center is measured on the prior pass, falling back to the center of the
+/// available space.
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// // Grow from half size.
+/// const GROW_IN: Animation = Animation::new_in(0.3, |ui, normal| {
+///     presets::scale(ui, 0.5 + normal * 0.5);
+/// });
+/// ```
+pub fn scale(ui: &mut egui::Ui, scale: f32) {
+    let center = content_rect(ui).center().to_vec2();
+
+    let transform = TSTransform::from_translation(center)
+        * TSTransform::from_scaling(scale)
+        * TSTransform::from_translation(-center);
+    ui.ctx().set_transform_layer(ui.layer_id(), transform);
+}
+
+/// Rotate the animation layer clockwise by the given `degrees`, about the center of the
+/// contents. The rect of the contents is measured on the prior pass, as with [`scale`].
+///
+/// `egui` layer transforms cannot rotate, so the shapes painted on the animation layer
+/// are rotated once the pass ends. This has some limitations:
+///
+/// - Shapes other than text and meshes are tessellated to rotate them, losing any
+///   texture-space anti-aliasing of their edges.
+/// - Text is rotated about its own position, and glyphs may not render as cleanly as
+///   upright text.
+/// - Paint callbacks are not rotated.
+/// - Clip rects are not rotated, so corners of rotated contents may be clipped by
+///   clipping containers.
+/// - Interaction is not rotated. Widgets respond within their upright rects.
+///
+/// As with other layer transforms, the entire layer is rotated. The segment must use an
+/// animation layer (see [`crate::AnimationSegment::with_layer`]).
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// // Spin in from a quarter turn, fading in.
+/// const SPIN_IN: Animation = Animation::new_in(0.3, |ui, normal| {
+///     presets::rotate(ui, (1.0 - normal) * -90.0);
+///     ui.set_opacity(normal);
+/// });
+/// ```
+pub fn rotate(ui: &mut egui::Ui, degrees: f32) {
+    let origin = content_rect(ui).center();
+
+    ui.ctx().add_plugin(RotatePlugin);
+    mem::insert_rotation(
+        ui.ctx(),
+        ui.layer_id(),
+        Rot2::from_angle(degrees.to_radians()),
+        origin,
+    );
+}
+
+/// Rotates the shapes of the layers rotated with [`rotate`] once the pass ends, before
+/// the layer transforms are applied.
+struct RotatePlugin;
+
+impl egui::Plugin for RotatePlugin {
+    fn debug_name(&self) -> &'static str {
+        "egui_animate::RotatePlugin"
+    }
+
+    fn on_end_pass(&mut self, ctx: &egui::Context) {
+        let rotations = mem::take_rotations(ctx);
+        if rotations.is_empty() {
+            return;
+        }
+        let mut tessellator = Tessellator::new(
+            ctx.pixels_per_point(),
+            ctx.tessellation_options(|options| *options),
+            ctx.fonts(|fonts| fonts.font_image_size()),
+            vec![],
+        );
+
+        ctx.graphics_mut(|graphics| {
+            for (layer_id, rot, origin) in rotations {
+                let Some(paint_list) = graphics.get_mut(layer_id) else {
+                    continue;
+                };
+                for idx in 0..paint_list.next_idx().0 {
+                    paint_list.mutate_shape(egui::layers::ShapeIdx(idx), |clipped| {
+                        rotate_shape(&mut tessellator, &mut clipped.shape, rot, origin);
+                    });
+                }
+            }
+        });
+    }
+}
+
+/// Rotate the given `shape` by `rot` about the `origin`, tessellating shapes that can
+/// not be rotated in place.
+fn rotate_shape(tessellator: &mut Tessellator, shape: &mut Shape, rot: Rot2, origin: Pos2) {
+    match shape {
+        Shape::Noop | Shape::Callback(_) => {}
+        Shape::Vec(shapes) => {
+            for shape in shapes {
+                rotate_shape(tessellator, shape, rot, origin);
+            }
+        }
+        Shape::Text(text) => {
+            text.pos = origin + rot * (text.pos - origin);
+            text.angle += rot.angle();
+        }
+        Shape::Mesh(mesh) => std::sync::Arc::make_mut(mesh).rotate(rot, origin),
+        _ => {
+            let mut mesh = egui::Mesh::default();
+            tessellator.tessellate_shape(std::mem::replace(shape, Shape::Noop), &mut mesh);
+            mesh.rotate(rot, origin);
+            *shape = Shape::mesh(mesh);
+        }
+    }
+}
+
+/// Clip the contents to the given `normal` of their width, revealing the contents from
+/// the left edge. The width is measured on the prior pass, falling back to the
+/// available space. The contents are not clipped at a `normal` of `1.0`.
+///
+/// The `normal` is clamped to `0.0..=1.0`, so overshooting easings neither grow the clip
+/// rect beyond the contents nor invert it.
+///
+/// Only the right edge of the existing clip rect is moved, composing with clipping
+/// containers such as [`egui::ScrollArea`].
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// const WIPE: Animation = Animation::new(0.4, reverse!(presets::clip_width), presets::clip_width);
+/// ```
+pub fn clip_width(ui: &mut egui::Ui, normal: f32) {
+    let normal = normal.clamp(0.0, 1.0);
+    if normal < 1.0 {
+        let rect = content_rect(ui);
+        let mut clip_rect = ui.clip_rect();
+        let right = rect.left() + rect.width() * normal;
+        clip_rect.max.x = clip_rect.max.x.min(right).max(clip_rect.min.x);
+        ui.set_clip_rect(clip_rect);
+    }
+}
+
+/// Clip the contents to the given `normal` of their height, revealing the contents from
+/// the top edge. See [`clip_width`].
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// const WIPE: Animation = Animation::new(0.4, reverse!(presets::clip_height), presets::clip_height);
+/// ```
+pub fn clip_height(ui: &mut egui::Ui, normal: f32) {
+    let normal = normal.clamp(0.0, 1.0);
+    if normal < 1.0 {
+        let rect = content_rect(ui);
+        let mut clip_rect = ui.clip_rect();
+        let bottom = rect.top() + rect.height() * normal;
+        clip_rect.max.y = clip_rect.max.y.min(bottom).max(clip_rect.min.y);
+        ui.set_clip_rect(clip_rect);
+    }
+}
+
+/// Discard the current pass for measuring the contents, unless `measured`. A pass is
+/// discarded at most once for the animation, so contents that remain empty (or
+/// collapsed) do not double the passes of every frame.
+fn discard_unmeasured(ui: &egui::Ui, measured: bool) {
+    let id = mem::get_animation_id(ui.ctx()).unwrap_or(ui.id());
+    match measured {
+        true => mem::clear_discarded(ui.ctx(), id),
+        false => {
+            if !mem::insert_discarded(ui.ctx(), id) {
+                ui.ctx().request_discard("egui_animate: measuring contents");
+            }
+        }
+    }
+}
+
+/// Get the rect of the contents measured on the prior pass, falling back to the
+/// available space.
+fn content_rect(ui: &egui::Ui) -> egui::Rect {
+    let rect = ui.response().rect;
+    match rect.is_positive() && rect.is_finite() {
+        true => rect,
+        false => ui.max_rect(),
+    }
+}
+
+/// Scale the animation layer up from nothing, overshooting to `1.0 + overshoot` before
+/// settling at full size, for the given `normal`. See [`scale`].
+///
+/// The peak is reached at 60% of the segment. An `overshoot` between `0.05` and `0.2`
+/// is recommended for subtle "pop in" effects, such as presenting buttons. The `normal`
+/// is clamped to `0.0..=1.0`.
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// const POP_IN: Animation = Animation::new_in(0.3, |ui, normal| {
+///     presets::pop(ui, normal, 0.1);
+/// });
+/// ```
+pub fn pop(ui: &mut egui::Ui, normal: f32, overshoot: f32) {
+    scale(ui, pop_scale(normal, overshoot));
+}
+
+/// Get the scale of [`pop`] for the given `normal` and `overshoot`.
+fn pop_scale(normal: f32, overshoot: f32) -> f32 {
+    const PEAK: f32 = 0.6;
+    let normal = normal.clamp(0.0, 1.0);
+
+    match normal < PEAK {
+        true => egui::lerp(0.0..=1.0 + overshoot, quadratic_out(normal / PEAK)),
+        false => egui::lerp(
+            1.0 + overshoot..=1.0,
+            quadratic_in_out((normal - PEAK) / (1.0 - PEAK)),
+        ),
+    }
+}
+
+/// Paint a translucent backdrop over the entire viewport, behind the animated contents,
+/// with an alpha of `max_alpha` scaled by the given `normal`. Approximates dimming (or
+/// blurring) the background of a modal dialog.
+///
+/// The backdrop is painted on the animation layer, and should be applied **after** any
+/// layer transforms, and **before** any opacity changes. As it is painted each frame,
+/// the backdrop is removed once the animation completes.
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// // Dim the background while presenting a dialog.
+/// const PRESENT_DIALOG: Animation = Animation::new_in(0.3, |ui, normal| {
+///     presets::dim_backdrop(ui, normal, 160);
+///     ui.set_opacity(normal);
+/// });
+/// ```
+pub fn dim_backdrop(ui: &mut egui::Ui, normal: f32, max_alpha: u8) {
+    let to_local = (ui.ctx().layer_transform_to_global(ui.layer_id()))
+        .map_or(TSTransform::IDENTITY, |transform| transform.inverse());
+    let rect = to_local * ui.ctx().viewport_rect();
+    let alpha = (max_alpha as f32 * normal.clamp(0.0, 1.0)).round() as u8;
+
+    ui.painter().with_clip_rect(rect).rect_filled(
+        rect,
+        0.0,
+        egui::Color32::from_black_alpha(alpha),
+    );
+}
+
+/// The color space used to interpolate colors.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorSpace {
+    /// Interpolate the gamma-encoded sRGB channels. Fast, but midtones are darker and
+    /// more muddy than either color.
+    #[default]
+    Gamma,
+    /// Interpolate in linear space, for perceptually smoother transitions.
+    Linear,
+}
+
+impl ColorSpace {
+    /// Interpolate between the `from` and `to` colors in this color space.
+    pub fn lerp(self, from: egui::Color32, to: egui::Color32, t: f32) -> egui::Color32 {
+        match self {
+            ColorSpace::Gamma => from.lerp_to_gamma(to, t),
+            ColorSpace::Linear => {
+                let (from, to) = (egui::Rgba::from(from), egui::Rgba::from(to));
+                (from * (1.0 - t) + to * t).into()
+            }
+        }
+    }
+}
+
+/// Tint the text color towards the given `target` color by the given `amount`, from
+/// the unchanged text color at `0.0` to the `target` at `1.0`. The colors are
+/// interpolated in the given color `space`.
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// # use egui_animate::presets::ColorSpace;
+/// // Fade in, settling from a red tint.
+/// const TINT_IN: Animation = Animation::new_in(0.3, |ui, normal| {
+///     presets::tint_text(ui, 1.0 - normal, egui::Color32::RED, ColorSpace::Linear);
+///     ui.set_opacity(normal);
+/// });
+/// ```
+pub fn tint_text(ui: &mut egui::Ui, amount: f32, target: egui::Color32, space: ColorSpace) {
+    let text_color = ui.visuals().text_color();
+    let tinted = space.lerp(text_color, target, amount.clamp(0.0, 1.0));
+    ui.visuals_mut().override_text_color = Some(tinted);
+}
+
+/// Fill the background of the contents with the color interpolated `from` and `to` by
+/// the given `normal`, such as a row highlighting when selected. The colors are
+/// interpolated in the given color `space`.
+///
+/// The fill is painted behind the contents, at the rect of the contents measured on the
+/// prior pass. A single pass is discarded while the contents have yet to be measured,
+/// and nothing is painted for contents that remain empty. As the fill is painted by the
+/// animation function, it is only painted while animating. Drive the `normal` with
+/// [`animate_progress`](crate::animate_progress) to keep the fill once settled.
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// # use egui_animate::presets::ColorSpace;
+/// # let selected = true;
+/// # let ctx = egui::Context::default();
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// fn highlight(ui: &mut egui::Ui, normal: f32) {
+///     let (from, to) = (egui::Color32::TRANSPARENT, egui::Color32::from_rgb(0, 92, 128));
+///     presets::fill_background(ui, normal, from, to, ColorSpace::Linear);
+/// }
+///
+/// let normal = ui.ctx().animate_bool("row".into(), selected);
+/// animate_progress(ui, "row", normal, highlight, |ui| {
+///     ui.label("Selectable row");
+/// });
+/// # });
+/// # });
+/// ```
+pub fn fill_background(
+    ui: &mut egui::Ui,
+    normal: f32,
+    from: egui::Color32,
+    to: egui::Color32,
+    space: ColorSpace,
+) {
+    let rect = ui.response().rect;
+    let measured = rect.is_positive() && rect.is_finite();
+    discard_unmeasured(ui, measured);
+    if measured {
+        let fill = space.lerp(from, to, normal.clamp(0.0, 1.0));
+        ui.painter().rect_filled(rect, 0.0, fill);
+    }
+}
+
+/// Create an `Animation` that collapses the allocated height of the prior value, and
+/// expands the allocated height of the new value, over the given total `duration`. See
+/// [`reveal_height`]. Eased with the default easing of the context (see
+/// [`AnimationSegment::with_default_easing`](crate::AnimationSegment::with_default_easing)).
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// # let mut expanded = false;
+/// # let ctx = egui::Context::default();
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// if ui.button("Toggle section").clicked() {
+///     expanded = !expanded;
+/// }
+/// animate(ui, "section", expanded, presets::expand_height(0.4), |ui, expanded| {
+///     if expanded {
+///         ui.label("Section contents");
+///     }
+/// });
+/// ui.label("Pushed down while expanding");
+/// # });
+/// # });
+/// ```
+pub const fn expand_height(duration: f32) -> Animation {
+    Animation::new(duration, crate::reverse!(reveal_height), reveal_height)
+        .with_clamp_normal(true)
+        .with_default_easing(true)
+}
+
+/// Allocate the height of the contents scaled by the given `normal`, sliding the
+/// contents down from the top of the allocated space. Unlike clipping, the surrounding
+/// layout reflows, pushing widgets below the contents as the height grows.
+///
+/// The height of the contents is measured on the prior pass, and a single pass is
+/// discarded while the contents have yet to be measured. Contents that remain empty
+/// allocate no height. Requires a top-down layout.
+pub fn reveal_height(ui: &mut egui::Ui, normal: f32) {
+    let height = ui.response().rect.height();
+    if normal < 1.0 {
+        discard_unmeasured(ui, height > 0.0);
+    }
+
+    let mut clip_rect = ui.clip_rect();
+    clip_rect.min.y = clip_rect.min.y.max(ui.cursor().top());
+    ui.set_clip_rect(clip_rect);
+    ui.add_space(-(1.0 - normal) * height.max(0.0));
+}
+
+/// Set the width of the contents to the width interpolated `from` and `to` by the given
+/// `normal`, such as a search box expanding on focus. Unlike clipping, the width is
+/// allocated, so neighboring widgets reflow as the width changes.
+///
+/// Sets both the minimum and maximum width, so wrapping contents resize with the width.
+/// The layout is recomputed each frame while animating, unlike the transform and clip
+/// presets, which only move or mask the painted contents.
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// const GROW: Animation = Animation::new(
+///     0.3,
+///     |ui, normal| presets::grow_width(ui, normal, 200.0, 80.0),
+///     |ui, normal| presets::grow_width(ui, normal, 80.0, 200.0),
+/// );
+/// ```
+pub fn grow_width(ui: &mut egui::Ui, normal: f32, from: f32, to: f32) {
+    ui.set_width(egui::lerp(from..=to, normal).max(0.0));
+}
+
+/// Set the height of the contents to the height interpolated `from` and `to` by the
+/// given `normal`. See [`grow_width`].
+pub fn grow_height(ui: &mut egui::Ui, normal: f32, from: f32, to: f32) {
+    ui.set_height(egui::lerp(from..=to, normal).max(0.0));
+}
+
+/// Evaluate the cubic Bézier curve of the given `control_points` at `t`, using De
+/// Casteljau's algorithm.
+pub fn cubic_bezier(control_points: [Vec2; 4], t: f32) -> Vec2 {
+    let [p0, p1, p2, p3] = control_points;
+
+    let (q0, q1, q2) = (lerp(p0, p1, t), lerp(p1, p2, t), lerp(p2, p3, t));
+    let (r0, r1) = (lerp(q0, q1, t), lerp(q1, q2, t));
+
+    lerp(r0, r1, t)
+}
+
+#[inline]
+fn lerp(from: Vec2, to: Vec2, t: f32) -> Vec2 {
+    egui::lerp(from..=to, t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::vec2;
+
+    const TEST_POINTS: [Vec2; 4] = [
+        vec2(-0.3, 0.7),
+        vec2(0.1, -12.9),
+        vec2(5.3, 0.2),
+        vec2(10.1, -0.3),
+    ];
+
+    #[test]
+    fn test_clip_within_scroll_area() {
+        let ctx = egui::Context::default();
+        let clip_rects = |normal: f32| {
+            let mut clip_rects = (egui::Rect::NOTHING, egui::Rect::NOTHING, 0.0);
+            let _ = ctx.run(Default::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    egui::ScrollArea::vertical()
+                        .max_height(50.0)
+                        .show(ui, |ui| {
+                            ui.scope(|ui| {
+                                let scroll_clip = ui.clip_rect();
+                                clip_height(ui, normal);
+                                clip_rects = (scroll_clip, ui.clip_rect(), ui.max_rect().top());
+                                ui.allocate_space(vec2(10.0, 200.0));
+                            });
+                        });
+                });
+            });
+            clip_rects
+        };
+        clip_rects(1.0);
+
+        // The contents extend past the scroll area, which remains the clip rect.
+        let (scroll_clip, clip, _) = clip_rects(1.0);
+        assert_eq!(clip, scroll_clip);
+        let (scroll_clip, clip, top) = clip_rects(0.1);
+        assert_eq!(clip.min, scroll_clip.min);
+        assert_eq!(clip.bottom(), top + 20.0);
+        let (_, clip, top) = clip_rects(0.0);
+        assert_eq!(clip.bottom(), top);
+    }
+
+    #[test]
+    fn test_slide_right_to_left() {
+        let ctx = egui::Context::default();
+        let slide_x = |layout: egui::Layout| {
+            let mut x = 0.0;
+            let _ = ctx.run(Default::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    ui.with_layout(layout, |ui| {
+                        slide(ui, vec2(10.0, 5.0));
+                        let transform = ui.ctx().layer_transform_to_global(ui.layer_id());
+                        x = transform.unwrap_or_default().translation.x;
+                    });
+                });
+            });
+            x
+        };
+
+        assert_eq!(slide_x(egui::Layout::left_to_right(egui::Align::Min)), 10.0);
+        assert_eq!(
+            slide_x(egui::Layout::right_to_left(egui::Align::Min)),
+            -10.0
+        );
+    }
+
+    #[test]
+    fn test_slide_fade_clip() {
+        let ctx = egui::Context::default();
+        let painted_clip = |normal: f32| {
+            let mut clip = (egui::Rect::NOTHING, egui::Rect::NOTHING);
+            let _ = ctx.run(Default::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let layer_id = egui::LayerId::new(egui::Order::Middle, egui::Id::new("slide"));
+                    ui.scope_builder(egui::UiBuilder::new().layer_id(layer_id), |ui| {
+                        slide_fade(ui, normal, vec2(10.0, 0.0));
+                        let transform = ui.ctx().layer_transform_to_global(layer_id);
+                        let transform = transform.unwrap_or_default();
+                        ui.allocate_space(vec2(20.0, 20.0));
+                        clip = (transform.mul_rect(ui.clip_rect()), ui.min_rect());
+                    });
+                });
+            });
+            clip
+        };
+        painted_clip(1.0);
+
+        // The painted clip rect stays at the contents at rest.
+        let (clip, rect) = painted_clip(1.0);
+        assert!(clip.contains_rect(rect) && clip != rect);
+        let (half_clip, rect) = painted_clip(0.5);
+        assert_eq!(half_clip, clip.intersect(rect));
+    }
+
+    #[test]
+    fn test_grow_width() {
+        let ctx = egui::Context::default();
+        let (mut width, mut neighbor_left) = (f32::NAN, f32::NAN);
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    let left = ui.cursor().left();
+                    width = ui
+                        .scope(|ui| grow_width(ui, 0.5, 100.0, 200.0))
+                        .response
+                        .rect
+                        .width();
+                    neighbor_left = ui.label("Neighbor").rect.left() - left;
+                });
+            });
+        });
+
+        assert_eq!(width, 150.0);
+        assert!(neighbor_left > 150.0);
+    }
+
+    #[test]
+    fn test_clip_overshoot() {
+        let ctx = egui::Context::default();
+        let clip_width = |normal: f32| {
+            let (mut width, mut full_width) = (f32::NAN, f32::NAN);
+            let _ = ctx.run(Default::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    full_width = ui.clip_rect().width();
+                    super::clip_width(ui, normal);
+                    width = ui.clip_rect().width();
+                });
+            });
+            (width, full_width)
+        };
+
+        for normal in [1.2, -0.1] {
+            let (width, full_width) = clip_width(normal);
+            assert!((0.0..=full_width).contains(&width));
+        }
+        assert_eq!(clip_width(-0.1), clip_width(0.0));
+        assert_eq!(clip_width(1.2), clip_width(1.0));
+    }
+
+    #[test]
+    fn test_default_easing() {
+        let ctx = egui::Context::default();
+        let clip_width = |segment: crate::AnimationSegment, normal: f32| {
+            let mut width = 0.0;
+            let _ = ctx.run(Default::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let rect = ui.max_rect();
+                    segment.apply(ui, normal);
+                    width = ui.clip_rect().width() / rect.width();
+                });
+            });
+            width
+        };
+        let linear = crate::AnimationSegment::new(1.0, super::clip_width);
+        let eased = linear.with_default_easing(true);
+        let reversed = crate::AnimationSegment::new(1.0, crate::reverse!(super::clip_width))
+            .with_default_easing(true);
+        crate::easing::set_default_easing(&ctx, quadratic_out);
+
+        // Presets are only eased by segments opting in.
+        assert!((clip_width(linear, 0.25) - 0.25).abs() < 1e-3);
+        assert!((clip_width(eased, 0.25) - quadratic_out(0.25)).abs() < 1e-3);
+        // Reversed functions mirror the eased normal.
+        assert!((clip_width(reversed, 0.25) - (1.0 - quadratic_out(0.25))).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_slide_fallback() {
+        let ctx = egui::Context::default();
+        let slid_rect = |offset: Vec2| {
+            let mut slid = (egui::Rect::NOTHING, None);
+            let _ = ctx.run(Default::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let layer_id = egui::LayerId::new(egui::Order::Middle, egui::Id::new("slide"));
+                    ui.scope_builder(egui::UiBuilder::new().layer_id(layer_id), |ui| {
+                        slide(ui, offset);
+                        let rect = ui.allocate_space(vec2(20.0, 20.0)).1;
+                        slid = (rect, ui.ctx().layer_transform_to_global(layer_id));
+                    });
+                });
+            });
+            slid
+        };
+        let (rest, _) = slid_rect(Vec2::ZERO);
+
+        // Vertical layouts consume the vertical offset, without a layer transform.
+        crate::set_slide_fallback(&ctx, true);
+        let (rect, transform) = slid_rect(vec2(5.0, 10.0));
+        assert_eq!(rect, rest.translate(vec2(0.0, 10.0)));
+        assert_eq!(transform, None);
+
+        // Negative offsets pull the contents back.
+        let (rect, _) = slid_rect(vec2(0.0, -10.0));
+        assert_eq!(rect, rest.translate(vec2(0.0, -10.0)));
+    }
+
+    #[test]
+    fn test_rotate_shape() {
+        let options = egui::epaint::TessellationOptions {
+            feathering: false,
+            ..Default::default()
+        };
+        let mut tessellator = Tessellator::new(1.0, options, [1, 1], vec![]);
+        let rot = Rot2::from_angle(90f32.to_radians());
+
+        // Shapes are tessellated and rotated clockwise about the origin.
+        let rect = egui::Rect::from_min_size(Pos2::ZERO, egui::vec2(20.0, 10.0));
+        let mut shape = Shape::rect_filled(rect, 0.0, egui::Color32::WHITE);
+        rotate_shape(&mut tessellator, &mut shape, rot, Pos2::ZERO);
+        let Shape::Mesh(mesh) = shape else {
+            panic!("expected a mesh");
+        };
+        let bounds = mesh.calc_bounds();
+        assert!((bounds.min - egui::pos2(-10.0, 0.0)).length() < 1e-4);
+        assert!((bounds.max - egui::pos2(0.0, 20.0)).length() < 1e-4);
+
+        // Text is rotated about its own position.
+        let ctx = egui::Context::default();
+        let _ = ctx.run(Default::default(), |ctx| {
+            let galley = ctx.fonts_mut(|fonts| {
+                fonts.layout_no_wrap("text".into(), Default::default(), egui::Color32::WHITE)
+            });
+            let mut shape = Shape::galley(egui::pos2(10.0, 0.0), galley, egui::Color32::WHITE);
+            rotate_shape(&mut tessellator, &mut shape, rot, Pos2::ZERO);
+            let Shape::Text(text) = shape else {
+                panic!("expected text");
+            };
+            assert!((text.pos - egui::pos2(0.0, 10.0)).length() < 1e-4);
+            assert_eq!(text.angle, rot.angle());
+        });
+    }
+
+    #[test]
+    fn test_color_space_midpoint() {
+        let (black, white) = (egui::Color32::BLACK, egui::Color32::WHITE);
+
+        // Gamma midtones are darker than the perceptual midpoint of linear space.
+        let gamma = ColorSpace::Gamma.lerp(black, white, 0.5);
+        let linear = ColorSpace::Linear.lerp(black, white, 0.5);
+        assert!((127..=128).contains(&gamma.r()));
+        assert!((187..=188).contains(&linear.r()));
+        assert_eq!(ColorSpace::Linear.lerp(black, white, 1.0), white);
+    }
+
+    #[test]
+    fn test_fill_background() {
+        let ctx = egui::Context::default();
+        let mut label_rect = egui::Rect::NOTHING;
+        let output = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                crate::animate_progress(
+                    ui,
+                    "test_fill",
+                    0.5,
+                    |ui, normal| {
+                        let (black, white) = (egui::Color32::BLACK, egui::Color32::WHITE);
+                        fill_background(ui, normal, black, white, ColorSpace::Gamma);
+                    },
+                    |ui| label_rect = ui.label("Contents").rect,
+                );
+            });
+        });
+
+        let fill = ColorSpace::Gamma.lerp(egui::Color32::BLACK, egui::Color32::WHITE, 0.5);
+        let rects: Vec<_> = (output.shapes.iter())
+            .filter_map(|clipped| match &clipped.shape {
+                Shape::Rect(rect) if rect.fill == fill => Some(rect.rect),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(rects, vec![label_rect]);
+    }
+
+    #[test]
+    fn test_fill_empty_discards_once() {
+        let ctx = egui::Context::default();
+        let run_frame = || {
+            let output = ctx.run(Default::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    crate::animate_progress(
+                        ui,
+                        "test_fill",
+                        0.5,
+                        |ui, normal| {
+                            let (black, white) = (egui::Color32::BLACK, egui::Color32::WHITE);
+                            fill_background(ui, normal, black, white, ColorSpace::Gamma);
+                        },
+                        |_| {},
+                    );
+                });
+            });
+            output.platform_output.num_completed_passes
+        };
+
+        assert_eq!(run_frame(), 2);
+        assert_eq!(run_frame(), 1);
+    }
+
+    #[test]
+    fn test_cubic_bezier_endpoints() {
+        assert_eq!(cubic_bezier(TEST_POINTS, 0.0), TEST_POINTS[0]);
+        assert_eq!(cubic_bezier(TEST_POINTS, 1.0), TEST_POINTS[3]);
+    }
+
+    #[test]
+    fn test_cubic_bezier_midpoint() {
+        let points = [
+            vec2(0.0, 0.0),
+            vec2(0.0, 4.0),
+            vec2(4.0, 4.0),
+            vec2(4.0, 0.0),
+        ];
+        assert_eq!(cubic_bezier(points, 0.5), vec2(2.0, 3.0));
+    }
+
+    #[test]
+    fn test_pop_scale() {
+        assert_eq!(pop_scale(0.0, 0.1), 0.0);
+        assert_eq!(pop_scale(0.6, 0.1), 1.1);
+        assert_eq!(pop_scale(1.0, 0.1), 1.0);
+        assert_eq!(pop_scale(1.5, 0.1), 1.0);
+        assert!(pop_scale(0.8, 0.1) > 1.0);
+    }
+
+    #[test]
+    fn test_expand_height_allocation() {
+        let ctx = egui::Context::default();
+        let run_frame = |time: f64, expanded: bool| {
+            let mut allocated = 0.0;
+            let input = egui::RawInput {
+                time: Some(time),
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let top = ui.cursor().top();
+                    crate::animate(
+                        ui,
+                        "expand",
+                        expanded,
+                        expand_height(1.0),
+                        |ui, expanded| {
+                            if expanded {
+                                ui.allocate_space(vec2(10.0, 100.0));
+                            }
+                        },
+                    );
+                    allocated = ui.cursor().top() - top;
+                });
+            });
+            allocated
+        };
+
+        let spacing = ctx.style().spacing.item_spacing.y;
+
+        assert_eq!(run_frame(0.0, false), 0.0);
+        assert_eq!(run_frame(1.0, true), spacing);
+        assert_eq!(run_frame(1.75, true), 50.0 + spacing);
+        assert_eq!(run_frame(2.0, true), 100.0 + spacing);
+    }
+
+    #[test]
+    fn test_expand_empty_discards_once() {
+        let ctx = egui::Context::default();
+        let run_frame = |time: f64, expanded: bool| {
+            let input = egui::RawInput {
+                time: Some(time),
+                ..Default::default()
+            };
+            let output = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    crate::animate(ui, "expand", expanded, expand_height(1.0), |_, _| {});
+                });
+            });
+            output.platform_output.num_completed_passes
+        };
+
+        assert_eq!(run_frame(0.0, false), 1);
+        assert_eq!(run_frame(1.0, true), 1);
+        // The empty contents are measured once, then no longer discarded.
+        assert_eq!(run_frame(1.5, true), 2);
+        assert_eq!(run_frame(1.6, true), 1);
+        assert_eq!(run_frame(1.7, true), 1);
+    }
+}
@@ -0,0 +1,42 @@
+//! A progress bar following a running animation.
+use crate::{Animation, run_state};
+
+/// Show an [`egui::ProgressBar`] of the overall progress of the animation of the given
+/// `id`. See [`RunState::progress`](crate::RunState::progress).
+///
+/// The bar is shown full while the animation is not running, as the animated value has
+/// finished transitioning. Useful for debugging, or for "loading" style transitions.
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # const MY_ANIM: Animation = Animation::EMPTY;
+/// # let mut my_state: u32 = 0;
+/// #
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// animate(ui, "my_anim", my_state, MY_ANIM, |ui, value| {
+///     // ...
+/// });
+///
+/// progress_bar(ui, "my_anim", MY_ANIM);
+/// #
+/// # });
+/// # });
+/// ```
+pub fn progress_bar(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    animation: impl Into<Animation>,
+) -> egui::Response {
+    let animation = animation.into();
+    let progress = run_state(ui, id, animation)
+        .progress(&animation)
+        .unwrap_or(1.0);
+    ui.add(egui::ProgressBar::new(progress))
+}
@@ -0,0 +1,190 @@
+//! Queued animations, playing value changes back to back.
+use std::any::Any;
+use std::collections::VecDeque;
+
+use crate::fps::{frame_interval, request_repaint_after};
+use crate::mem;
+use crate::{Animation, AnimationResult, animate};
+
+/// How [`animate_queued`] queues values changed while an animation is running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnimationQueue {
+    /// Play every queued value in order, with at most the given number of pending
+    /// values. Once full, the newest pending value is replaced.
+    All(usize),
+    /// Play only the latest value, dropping intermediate values.
+    Latest,
+}
+
+impl AnimationQueue {
+    /// Get the maximum number of pending values.
+    fn capacity(&self) -> usize {
+        match self {
+            AnimationQueue::All(capacity) => (*capacity).max(1),
+            AnimationQueue::Latest => 1,
+        }
+    }
+}
+
+/// The animated target value, and the values pending animation.
+#[derive(Clone)]
+struct Queue<T> {
+    target: T,
+    pending: VecDeque<T>,
+}
+
+/// Create an animation that transitions between changes of the given `value`, queueing
+/// changes made while an animation is running to play back to back.
+///
+/// Unlike [`animate`], changing the value mid-animation does not interrupt the running
+/// animation. See [`AnimationQueue`] for how pending values are kept.
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # const MY_ANIM: Animation = Animation::EMPTY;
+/// let mut my_state: u32 = 0;
+///
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// if ui.button("Increment").clicked() {
+///     my_state += 1;
+/// }
+/// animate_queued(ui, "my_anim", my_state, MY_ANIM, AnimationQueue::All(4), |ui, value| {
+///     ui.label(format!("{value}"));
+/// });
+/// #
+/// # });
+/// # });
+/// ```
+pub fn animate_queued<T: 'static + Any + Clone + Send + Sync + Default + PartialEq, R>(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    value: T,
+    animation: impl Into<Animation>,
+    queue: AnimationQueue,
+    add_contents: impl FnOnce(&mut egui::Ui, T) -> R,
+) -> AnimationResult {
+    let id: egui::Id = id.into();
+
+    let mut state = mem::get_or_insert_queue(ui, id, || Queue {
+        target: value.clone(),
+        pending: VecDeque::new(),
+    });
+    let is_running = mem::get_start_time(ui, id).is_some();
+    // Animate from the prior target, as finished animations clear their start value.
+    mem::get_or_insert_start_value(ui, id, state.target.clone());
+
+    let requested = state.pending.back().unwrap_or(&state.target);
+    if *requested != value {
+        match is_running {
+            true if state.pending.len() >= queue.capacity() => {
+                state.pending.pop_back();
+                state.pending.push_back(value);
+            }
+            true => state.pending.push_back(value),
+            false => state.target = value,
+        }
+    }
+    if !is_running && let Some(next) = state.pending.pop_front() {
+        state.target = next;
+    }
+
+    let target = state.target.clone();
+    let drained = state.pending.is_empty();
+    if !drained {
+        request_repaint_after(ui.ctx(), frame_interval(ui.ctx()));
+    }
+    mem::insert_queue(ui, id, state);
+
+    let result = animate(ui, id, target.clone(), animation, add_contents);
+    if drained && !result.run_state.is_running() {
+        // Keep the target as the start value of the next animation, in place of the queue.
+        mem::get_or_insert_start_value(ui, id, target);
+        mem::clear_queue::<Queue<T>>(ui, id);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_ANIM: Animation = Animation::new(1.0, |_, _| {}, |_, _| {});
+
+    /// Run a single frame at the given `time`, returning the value passed to the contents.
+    fn run_frame(ctx: &egui::Context, time: f64, value: u32, queue: AnimationQueue) -> u32 {
+        let mut displayed = 0;
+        let input = egui::RawInput {
+            time: Some(time),
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                animate_queued(ui, "test_anim", value, TEST_ANIM, queue, |_, value| {
+                    displayed = value;
+                });
+            });
+        });
+        displayed
+    }
+
+    #[test]
+    fn test_queue_all() {
+        let ctx = egui::Context::default();
+        let queue = AnimationQueue::All(2);
+
+        assert_eq!(run_frame(&ctx, 0.0, 0, queue), 0);
+        assert_eq!(run_frame(&ctx, 1.0, 1, queue), 0);
+        assert_eq!(run_frame(&ctx, 1.25, 2, queue), 0);
+        assert_eq!(run_frame(&ctx, 1.5, 3, queue), 1);
+        // The first animation finishes, then the queued values play in order.
+        assert_eq!(run_frame(&ctx, 2.0, 3, queue), 1);
+        assert_eq!(run_frame(&ctx, 2.1, 3, queue), 1);
+        assert_eq!(run_frame(&ctx, 2.6, 3, queue), 2);
+        assert_eq!(run_frame(&ctx, 3.1, 3, queue), 2);
+        assert_eq!(run_frame(&ctx, 3.2, 3, queue), 2);
+        assert_eq!(run_frame(&ctx, 3.7, 3, queue), 3);
+        assert_eq!(run_frame(&ctx, 4.2, 3, queue), 3);
+    }
+
+    #[test]
+    fn test_clear_drained_queue() {
+        let ctx = egui::Context::default();
+        let queue = AnimationQueue::All(2);
+        let has_queue = |ctx: &egui::Context| {
+            let id = egui::Id::new("test_anim").with("queue");
+            ctx.memory(|m| m.data.get_temp::<Queue<u32>>(id).is_some())
+        };
+
+        assert_eq!(run_frame(&ctx, 0.0, 0, queue), 0);
+        assert!(!has_queue(&ctx));
+        assert_eq!(run_frame(&ctx, 1.0, 1, queue), 0);
+        assert!(has_queue(&ctx));
+        assert_eq!(run_frame(&ctx, 2.0, 1, queue), 1);
+        assert!(!has_queue(&ctx));
+        // Changes right after draining still animate from the prior target.
+        assert_eq!(run_frame(&ctx, 2.1, 2, queue), 1);
+        assert_eq!(run_frame(&ctx, 2.85, 2, queue), 2);
+    }
+
+    #[test]
+    fn test_queue_latest() {
+        let ctx = egui::Context::default();
+        let queue = AnimationQueue::Latest;
+
+        assert_eq!(run_frame(&ctx, 0.0, 0, queue), 0);
+        assert_eq!(run_frame(&ctx, 1.0, 1, queue), 0);
+        assert_eq!(run_frame(&ctx, 1.25, 2, queue), 0);
+        assert_eq!(run_frame(&ctx, 1.5, 3, queue), 1);
+        assert_eq!(run_frame(&ctx, 2.0, 3, queue), 1);
+        // The intermediate value is dropped.
+        assert_eq!(run_frame(&ctx, 2.1, 3, queue), 1);
+        assert_eq!(run_frame(&ctx, 2.6, 3, queue), 3);
+    }
+}
@@ -0,0 +1,160 @@
+//! Layout animations following changes of a rect.
+use crate::enabled::animation_enabled;
+use crate::fps::{frame_interval, frame_time, request_repaint_after};
+use crate::mem;
+
+/// Show the contents within the given `target` rect, sliding the contents from the prior
+/// rect to the new rect over the given `duration` (in seconds) when the `target` changes,
+/// such as when reordering a list.
+///
+/// The contents are laid out within the `target` rect, and translated on a separate
+/// layer from the prior position. Only the position is animated; changes in size apply
+/// immediately. Changing the target mid-slide continues from the currently displayed
+/// position. Disabled animations (see
+/// [`set_animation_enabled`](crate::set_animation_enabled)) show the contents at the
+/// `target` immediately.
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// let mut items = vec!["First", "Second", "Third"];
+///
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// if ui.button("Shuffle").clicked() {
+///     items.rotate_left(1);
+/// }
+/// let origin = ui.cursor().min;
+/// for (index, item) in items.iter().enumerate() {
+///     let rect = egui::Rect::from_min_size(
+///         origin + egui::vec2(0.0, index as f32 * 24.0),
+///         egui::vec2(120.0, 20.0),
+///     );
+///     animate_rect(ui, *item, rect, 0.3, |ui| ui.label(*item));
+/// }
+/// #
+/// # });
+/// # });
+/// ```
+pub fn animate_rect<R>(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    target: egui::Rect,
+    duration: f32,
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> egui::InnerResponse<R> {
+    let id: egui::Id = id.into();
+    let ctx = ui.ctx().clone();
+    let current_time = ctx.input(|input| input.time);
+    let enabled = animation_enabled(&ctx, id);
+
+    // The rect displayed for the given slide, snapping to the target without duration.
+    let displayed = |(from, to, start_time): mem::Slide| match start_time {
+        Some(start_time) if enabled && duration > 0.0 => {
+            let t = (frame_time(&ctx, start_time, current_time) - start_time) / duration as f64;
+            let offset = from.min.lerp(to.min, t.clamp(0.0, 1.0) as f32) - to.min;
+            to.translate(offset)
+        }
+        _ => to,
+    };
+
+    let mut slide = mem::get_or_insert_slide(ui, id, target);
+    if slide.1 != target {
+        slide = (displayed(slide), target, Some(current_time));
+    }
+    let displayed = displayed(slide);
+    match displayed == target {
+        true => slide = (target, target, None),
+        false => request_repaint_after(&ctx, frame_interval(&ctx)),
+    }
+    mem::insert_slide(ui, id, slide);
+
+    let layer_id = egui::LayerId::new(ui.layer_id().order, id);
+    ui.ctx().set_sublayer(ui.layer_id(), layer_id);
+    let offset = displayed.min - target.min;
+    match offset == egui::Vec2::ZERO {
+        true => mem::clear_animation_layers(ui.ctx(), id),
+        false => ui
+            .ctx()
+            .set_transform_layer(layer_id, egui::emath::TSTransform::from_translation(offset)),
+    }
+
+    ui.scope_builder(
+        egui::UiBuilder::new()
+            .id_salt(id)
+            .layer_id(layer_id)
+            .max_rect(target),
+        add_contents,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run a single frame at the given `time`, returning the layer translation.
+    fn run_frame(ctx: &egui::Context, time: f64, target: egui::Rect, duration: f32) -> egui::Vec2 {
+        let input = egui::RawInput {
+            time: Some(time),
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                animate_rect(ui, "test_rect", target, duration, |ui| ui.label("Item"));
+            });
+        });
+        let layer_id = egui::LayerId::new(egui::Order::Background, "test_rect".into());
+        ctx.layer_transform_to_global(layer_id)
+            .unwrap_or_default()
+            .translation
+    }
+
+    #[test]
+    fn test_animate_rect() {
+        let ctx = egui::Context::default();
+        let size = egui::vec2(100.0, 20.0);
+        let top = egui::Rect::from_min_size(egui::pos2(0.0, 0.0), size);
+        let bottom = egui::Rect::from_min_size(egui::pos2(0.0, 100.0), size);
+
+        assert_eq!(run_frame(&ctx, 0.0, top, 1.0), egui::Vec2::ZERO);
+        assert_eq!(run_frame(&ctx, 1.0, bottom, 1.0), egui::vec2(0.0, -100.0));
+        assert_eq!(run_frame(&ctx, 1.5, bottom, 1.0), egui::vec2(0.0, -50.0));
+        // Retarget mid-slide from the displayed position.
+        assert_eq!(run_frame(&ctx, 1.5, top, 1.0), egui::vec2(0.0, 50.0));
+        assert_eq!(run_frame(&ctx, 2.0, top, 1.0), egui::vec2(0.0, 25.0));
+        assert_eq!(run_frame(&ctx, 2.5, top, 1.0), egui::Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_disabled() {
+        let ctx = egui::Context::default();
+        let size = egui::vec2(100.0, 20.0);
+        let top = egui::Rect::from_min_size(egui::pos2(0.0, 0.0), size);
+        let bottom = egui::Rect::from_min_size(egui::pos2(0.0, 100.0), size);
+
+        run_frame(&ctx, 0.0, top, 1.0);
+        assert_eq!(run_frame(&ctx, 1.0, bottom, 1.0), egui::vec2(0.0, -100.0));
+        // Disabling mid-slide shows the contents at the target.
+        crate::set_animation_enabled(&ctx, "test_rect", false);
+        assert_eq!(run_frame(&ctx, 1.5, bottom, 1.0), egui::Vec2::ZERO);
+        assert_eq!(run_frame(&ctx, 2.0, top, 1.0), egui::Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_zero_duration() {
+        let ctx = egui::Context::default();
+        let size = egui::vec2(100.0, 20.0);
+        let top = egui::Rect::from_min_size(egui::pos2(0.0, 0.0), size);
+        let bottom = egui::Rect::from_min_size(egui::pos2(0.0, 100.0), size);
+
+        assert_eq!(run_frame(&ctx, 0.0, top, 0.0), egui::Vec2::ZERO);
+        // Snaps to the target, rather than interpolating by a NaN.
+        assert_eq!(run_frame(&ctx, 1.0, bottom, 0.0), egui::Vec2::ZERO);
+        assert_eq!(run_frame(&ctx, 1.0, top, 0.0), egui::Vec2::ZERO);
+    }
+}
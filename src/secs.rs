@@ -0,0 +1,93 @@
+//! Validated animation durations.
+use std::fmt;
+
+/// A non-negative, finite duration in seconds.
+///
+/// Validates durations at the boundary, catching negative or infinite durations before
+/// they reach an [`Animation`](crate::Animation). Created with `Secs::try_from(f32)`,
+/// [`Secs::saturating`], or from a [`std::time::Duration`]. See
+/// [`Animation::from_secs`](crate::Animation::from_secs).
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// let duration = Secs::try_from(0.3).unwrap();
+/// assert_eq!(duration.get(), 0.3);
+///
+/// assert!(Secs::try_from(-1.0).is_err());
+/// assert_eq!(Secs::from(std::time::Duration::from_millis(500)).get(), 0.5);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Secs(f32);
+
+impl Secs {
+    /// A zero duration.
+    pub const ZERO: Secs = Secs(0.0);
+
+    /// Create a new `Secs`, clamping the duration to the valid range. Negative and `NaN`
+    /// durations become `0.0`, and infinite durations become `f32::MAX`.
+    pub const fn saturating(secs: f32) -> Self {
+        Self(secs.max(0.0).min(f32::MAX))
+    }
+
+    /// Get the duration in seconds.
+    pub const fn get(self) -> f32 {
+        self.0
+    }
+}
+
+impl TryFrom<f32> for Secs {
+    type Error = InvalidSecs;
+
+    fn try_from(secs: f32) -> Result<Self, Self::Error> {
+        match secs.is_finite() && secs >= 0.0 {
+            true => Ok(Self(secs)),
+            false => Err(InvalidSecs(secs)),
+        }
+    }
+}
+
+impl From<std::time::Duration> for Secs {
+    fn from(duration: std::time::Duration) -> Self {
+        Self(duration.as_secs_f32())
+    }
+}
+
+impl From<Secs> for f32 {
+    fn from(secs: Secs) -> Self {
+        secs.0
+    }
+}
+
+/// The error of a negative or non-finite duration, converted into [`Secs`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InvalidSecs(pub f32);
+
+impl fmt::Display for InvalidSecs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid animation duration: {} seconds", self.0)
+    }
+}
+
+impl std::error::Error for InvalidSecs {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from() {
+        assert_eq!(Secs::try_from(-1.0), Err(InvalidSecs(-1.0)));
+        assert!(Secs::try_from(f32::INFINITY).is_err());
+        assert!(Secs::try_from(f32::NAN).is_err());
+        assert_eq!(Secs::try_from(0.25).map(f32::from), Ok(0.25));
+    }
+
+    #[test]
+    fn test_saturating() {
+        assert_eq!(Secs::saturating(-1.0), Secs::ZERO);
+        assert_eq!(Secs::saturating(f32::NAN), Secs::ZERO);
+        assert_eq!(Secs::saturating(f32::INFINITY).get(), f32::MAX);
+        assert_eq!(Secs::saturating(0.25).get(), 0.25);
+    }
+}
@@ -0,0 +1,80 @@
+//! Deterministic per-animation random values, for jittered or organic effects.
+use crate::mem;
+
+/// Get the seed of the animation currently being applied, derived from its
+/// [`egui::Id`]. The seed is stable across frames, and should be called from within
+/// an animation function.
+///
+/// Returns `0` outside of an animation function.
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// // Fade in, with each animation offset by a small random amount.
+/// const JITTER_IN: Animation = Animation::new_in(0.3, |ui, normal| {
+///     let seed = animation_seed(ui);
+///     let offset = egui::vec2(jitter(seed, 0), jitter(seed, 1)) * 4.0 * (1.0 - normal);
+///
+///     let transform = egui::emath::TSTransform::from_translation(offset);
+///     ui.ctx().set_transform_layer(ui.layer_id(), transform);
+///     ui.set_opacity(normal);
+/// });
+/// ```
+pub fn animation_seed(ui: &egui::Ui) -> u64 {
+    mem::get_animation_id(ui.ctx()).map_or(0, |id| id.value())
+}
+
+/// Get a deterministic pseudo-random value between `-1.0` and `1.0` for the given
+/// `seed` and `index`. Distinct indices produce independent values for the same seed.
+pub fn jitter(seed: u64, index: u64) -> f32 {
+    // SplitMix64, keeping the upper 24 bits for an `f32` mantissa.
+    let mut z = seed.wrapping_add(index.wrapping_add(1).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+
+    (z >> 40) as f32 / (1u64 << 23) as f32 - 1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jitter() {
+        assert_eq!(jitter(42, 0), jitter(42, 0));
+        assert_ne!(jitter(42, 0), jitter(42, 1));
+        assert_ne!(jitter(42, 0), jitter(43, 0));
+
+        for index in 0..1000 {
+            assert!((-1.0..1.0).contains(&jitter(7, index)));
+        }
+    }
+
+    #[test]
+    fn test_animation_seed() {
+        const SEEDED_ANIM: crate::Animation = crate::Animation::new(
+            1.0,
+            |ui, _| assert_eq!(animation_seed(ui), egui::Id::new("test_anim").value()),
+            |ui, _| assert_eq!(animation_seed(ui), egui::Id::new("test_anim").value()),
+        );
+        let ctx = egui::Context::default();
+        let run_frame = |time: f64, value: i32| {
+            let input = egui::RawInput {
+                time: Some(time),
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    crate::animate(ui, "test_anim", value, SEEDED_ANIM, |_, _| {});
+                    // The seed is scoped to the animation function.
+                    assert_eq!(animation_seed(ui), 0);
+                });
+            });
+        };
+
+        run_frame(0.0, 0);
+        run_frame(1.0, 1);
+        run_frame(1.75, 1);
+    }
+}
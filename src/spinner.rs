@@ -0,0 +1,106 @@
+//! Continuously looping animations, such as loading spinners.
+use std::f32::consts::TAU;
+
+use crate::fps::{frame_interval, frame_time, request_repaint_after};
+use crate::mem;
+
+/// Loop a *normal* from `0.0` to `1.0` every `duration` seconds for as long as the
+/// spinner of the given `id` is shown, calling `draw` with the normal of the current
+/// frame.
+///
+/// Unlike [`animate`](crate::animate), spinners do not wait for a changing value, and
+/// have no *out*/*in* segments. Repaints are requested every frame, respecting the
+/// maximum frame rate (see [`set_max_fps`](crate::set_max_fps)). See [`spinner_arc`]
+/// for a default spinner.
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # let ctx = egui::Context::default();
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// // A rotating arc, completing a turn every second.
+/// spinner_animated(ui, "loading", 1.0, spinner_arc);
+///
+/// // A pulsing label.
+/// spinner_animated(ui, "pulse", 2.0, |ui, normal| {
+///     ui.set_opacity(0.5 + 0.5 * (normal * std::f32::consts::TAU).cos());
+///     ui.label("Loading...");
+/// });
+/// # });
+/// # });
+/// ```
+pub fn spinner_animated<R>(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    duration: f32,
+    draw: impl FnOnce(&mut egui::Ui, f32) -> R,
+) -> R {
+    let id: egui::Id = id.into();
+    let current_time = ui.ctx().input(|input| input.time);
+    let start_time = mem::get_or_insert_start_time(ui, id, current_time);
+    let elapsed = frame_time(ui.ctx(), start_time, current_time) - start_time;
+
+    let normal = match duration > 0.0 {
+        true => (elapsed / duration as f64).fract() as f32,
+        false => 0.0,
+    };
+    request_repaint_after(ui.ctx(), frame_interval(ui.ctx()));
+
+    ui.push_id(id, |ui| draw(ui, normal)).inner
+}
+
+/// Draw a quarter-turn arc rotated by the given `normal` of a full turn, sized to the
+/// height of an interactive widget. For use with [`spinner_animated`].
+pub fn spinner_arc(ui: &mut egui::Ui, normal: f32) -> egui::Response {
+    let size = ui.spacing().interact_size.y;
+    let (rect, response) = ui.allocate_exact_size(egui::vec2(size, size), egui::Sense::hover());
+
+    if ui.is_rect_visible(rect) {
+        const SEGMENTS: usize = 16;
+        let radius = rect.height() / 2.0 - 2.0;
+        let start_angle = normal * TAU;
+        let points = (0..=SEGMENTS)
+            .map(|i| {
+                let angle = start_angle + TAU * 0.75 * i as f32 / SEGMENTS as f32;
+                rect.center() + radius * egui::vec2(angle.cos(), angle.sin())
+            })
+            .collect();
+        let stroke = egui::Stroke::new(2.0, ui.visuals().strong_text_color());
+        ui.painter().add(egui::Shape::line(points, stroke));
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run a single frame at the given `time`, returning the spinner normal.
+    fn run_frame(ctx: &egui::Context, time: f64) -> f32 {
+        let mut normal = f32::NAN;
+        let input = egui::RawInput {
+            time: Some(time),
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                normal = spinner_animated(ui, "test_spinner", 2.0, |_, normal| normal);
+            });
+        });
+        normal
+    }
+
+    #[test]
+    fn test_spinner_animated() {
+        let ctx = egui::Context::default();
+
+        // Loops from the first frame shown, without a changing value.
+        assert_eq!(run_frame(&ctx, 1.0), 0.0);
+        assert_eq!(run_frame(&ctx, 1.5), 0.25);
+        assert_eq!(run_frame(&ctx, 3.5), 0.25);
+        assert_eq!(run_frame(&ctx, 4.0), 0.5);
+    }
+}
@@ -1,12 +1,32 @@
 use std::any::Any;
 
-use crate::Animation;
+use crate::accessibility::with_accessibility;
+use crate::budget::over_frame_budget;
+use crate::clock::AnimationClock;
+use crate::debug::with_debug_draw;
+use crate::enabled::animation_enabled;
+use crate::fps::{frame_interval, frame_time, request_repaint_after};
 use crate::mem;
+use crate::{Animation, AnimationSegment, AnimationStats};
+
+const OVERLAP_SUFFIX: &str = "overlap";
 
 /// Create an animation that transitions between changes of the given `value`.
 ///
 /// Requires a unique [`egui::Id`], and [`Animation`]. See [`Animation`] for details
-/// on how to define an animation.
+/// on how to define an animation, or pass a `(duration, out_fn, in_fn)` tuple. Animating
+/// an id more than once in a frame logs a warning in debug builds.
+///
+/// The durations of the animation are kept from the frame the animation starts. Passing
+/// a different animation mid-flight changes only the animation functions, avoiding jumps
+/// in the *normal*.
+///
+/// Reverting the value to the start value of a running animation plays the animation
+/// backward from its current point, rather than snapping to the start value.
+///
+/// Running animations scrolled outside of the clip rect skip their animation functions
+/// and per-frame repaints, showing the new value. The animation resumes from the elapsed
+/// time once scrolled back into view.
 ///
 /// # Example
 /// ```
@@ -42,29 +62,456 @@ pub fn animate<T: 'static + Any + Clone + Send + Sync + Default + PartialEq, R>(
     ui: &mut egui::Ui,
     id: impl Into<egui::Id>,
     value: T,
-    animation: Animation,
+    animation: impl Into<Animation>,
     add_contents: impl FnOnce(&mut egui::Ui, T) -> R,
-) {
-    let id: egui::Id = id.into();
+) -> AnimationResult {
+    let animation = animation.into();
+    animate_with(ui, id.into(), value, |_, _| animation, add_contents, |_| {})
+}
 
-    let current_time = ui.ctx().input(|input| input.time);
+/// Create an animation that transitions between changes of the given `value`, with an
+/// id derived from the given `salt` and the id of the `ui`.
+///
+/// Follows the `egui` id salt conventions, such as [`egui::UiBuilder::id_salt`]. Useful
+/// for reusable components, where the `salt` only needs to be unique within the `ui`,
+/// rather than globally. See [`animate`].
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # const FADE_ANIM: Animation = Animation::EMPTY;
+/// fn my_component(ui: &mut egui::Ui, value: u32) {
+///     animate_salted(ui, "value", value, FADE_ANIM, |ui, value| {
+///         ui.label(format!("Value is {}", value));
+///     });
+/// }
+///
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// // Each component animates independently.
+/// ui.push_id("first", |ui| my_component(ui, 1));
+/// ui.push_id("second", |ui| my_component(ui, 2));
+/// #
+/// # });
+/// # });
+/// ```
+pub fn animate_salted<T: 'static + Any + Clone + Send + Sync + Default + PartialEq, R>(
+    ui: &mut egui::Ui,
+    salt: impl std::hash::Hash,
+    value: T,
+    animation: impl Into<Animation>,
+    add_contents: impl FnOnce(&mut egui::Ui, T) -> R,
+) -> AnimationResult {
+    let id = ui.id().with(salt);
+    let animation = animation.into();
+    animate_with(ui, id, value, |_, _| animation, add_contents, |_| {})
+}
+
+/// Create an animation that transitions between changes of the given `value`, calling
+/// `on_frame` with the [`RunState`] before `add_contents` on every frame the animation is
+/// running. See [`animate`].
+///
+/// Unlike the `just_started` and `just_finished` flags of the [`AnimationResult`],
+/// `on_frame` is called continuously, regardless of the segment. It is never called with
+/// [`RunState::None`], such as for effects (e.g. particles or sound) that follow the
+/// animation.
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # const FADE_ANIM: Animation = Animation::EMPTY;
+/// # let mut my_state: u32 = 0;
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// animate_with_on_frame(
+///     ui,
+///     "my_fade",
+///     my_state,
+///     FADE_ANIM,
+///     |ui, value| {
+///         ui.label(format!("Value is {}", value));
+///     },
+///     |run_state| {
+///         // Follow the animation, e.g. with the pitch of a sound.
+///         let presence = run_state.presence();
+///     },
+/// );
+/// #
+/// # });
+/// # });
+/// ```
+pub fn animate_with_on_frame<T: 'static + Any + Clone + Send + Sync + Default + PartialEq, R>(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    value: T,
+    animation: impl Into<Animation>,
+    add_contents: impl FnOnce(&mut egui::Ui, T) -> R,
+    on_frame: impl FnOnce(RunState),
+) -> AnimationResult {
+    animate_with(
+        ui,
+        id.into(),
+        value,
+        |_, _| animation.into(),
+        add_contents,
+        on_frame,
+    )
+}
+
+/// Create an animation that transitions between changes of the given `value`, rendering
+/// the prior and new values together for the overlap of the `animation`. See [`animate`].
+///
+/// Unlike [`animate`], which plays the segments in sequence, `add_contents` is called
+/// twice per frame while the segments overlap, once for each value. Nested animations
+/// within `add_contents` should derive their ids from the `ui` (e.g. with
+/// [`animate_salted`]), as each value is added within a separate child `ui`.
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # let mut my_state: u32 = 0;
+/// #
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// animate_crossfade(ui, "my_crossfade", my_state, Animation::crossfade(0.3), |ui, value| {
+///     ui.label(format!("Value is {}", value));
+/// });
+/// #
+/// # });
+/// # });
+/// ```
+pub fn animate_crossfade<T: 'static + Any + Clone + Send + Sync + Default + PartialEq, R>(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    value: T,
+    animation: impl Into<Animation>,
+    add_contents: impl FnMut(&mut egui::Ui, T) -> R,
+) -> AnimationResult {
+    let animation = animation.into();
+    animate_with_overlap(
+        ui,
+        id.into(),
+        value,
+        TimeSource::Seconds,
+        |_, _| animation,
+        add_contents,
+        |_| {},
+    )
+}
+
+/// Create an animation that transitions between changes of the given `value`, getting
+/// the [`Animation`] from the `from` and `to` values on change, and calling `on_frame`
+/// while running. The segments are played in sequence, ignoring any overlap.
+pub(crate) fn animate_with<T: 'static + Any + Clone + Send + Sync + Default + PartialEq, R>(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    value: T,
+    animation: impl FnOnce(&T, &T) -> Animation,
+    add_contents: impl FnOnce(&mut egui::Ui, T) -> R,
+    on_frame: impl FnOnce(RunState),
+) -> AnimationResult {
+    animate_with_source(
+        ui,
+        id,
+        value,
+        TimeSource::Seconds,
+        animation,
+        add_contents,
+        on_frame,
+    )
+}
+
+/// Create an animation that transitions between changes of the given `value`, counting
+/// time in the given `source`. See [`animate_with`].
+fn animate_with_source<T: 'static + Any + Clone + Send + Sync + Default + PartialEq, R>(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    value: T,
+    source: TimeSource,
+    animation: impl FnOnce(&T, &T) -> Animation,
+    add_contents: impl FnOnce(&mut egui::Ui, T) -> R,
+    on_frame: impl FnOnce(RunState),
+) -> AnimationResult {
+    let mut add_contents = Some(add_contents);
+    animate_with_overlap(
+        ui,
+        id,
+        value,
+        source,
+        |from, to| animation(from, to).with_overlap(0.0),
+        |ui, value| {
+            if let Some(add_contents) = add_contents.take() {
+                add_contents(ui, value);
+            }
+        },
+        on_frame,
+    )
+}
+
+/// Create an animation that transitions between changes of the given `value`, getting
+/// the [`Animation`] from the `from` and `to` values on change, and calling `on_frame`
+/// while running. Overlapping segments call `add_contents` for both values.
+fn animate_with_overlap<T: 'static + Any + Clone + Send + Sync + Default + PartialEq, R>(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    value: T,
+    source: TimeSource,
+    animation: impl FnOnce(&T, &T) -> Animation,
+    mut add_contents: impl FnMut(&mut egui::Ui, T) -> R,
+    on_frame: impl FnOnce(RunState),
+) -> AnimationResult {
+    #[cfg(debug_assertions)]
+    if mem::mark_animated(ui.ctx(), id) {
+        log::warn!("egui_animate: animation id {id:?} is animated more than once in a frame");
+    }
+
+    if !animation_enabled(ui.ctx(), id) {
+        return animate_disabled(ui, id, value, add_contents);
+    }
+
+    let current_time = source.current_time(ui.ctx());
+    // The id keeping the start time and clock, counted in the time source.
+    let time_id = source.time_id(id);
     let current_value = value;
-    let start_value = mem::get_or_insert_start_value(ui, id, current_value.clone());
+    let mut start_value = mem::get_or_insert_start_value(ui, id, current_value.clone());
+
+    // Play running animations backward once the value is reverted to the start value.
+    if start_value == current_value
+        && let Some(target_value) = mem::get_target_value::<T>(ui, id)
+        && target_value != current_value
+        && let (Some(start_time), Some(clock)) = (
+            mem::get_start_time(ui, time_id),
+            mem::get_clock(ui, time_id),
+        )
+    {
+        let clock = AnimationClock {
+            start_time,
+            current_time,
+            ..clock
+        }
+        .reversed();
+        mem::insert_start_time(ui, time_id, clock.start_time);
+        mem::insert_clock(ui, time_id, clock);
+        mem::insert_start_value(ui, id, target_value.clone());
+        start_value = target_value;
+    }
 
-    match start_value == current_value {
-        true => add_contents(ui, current_value),
+    match start_value == current_value && !mem::get_retrigger(ui, id) {
+        true => {
+            // Reset the timing left by a partially cleared memory (e.g. the start value).
+            if mem::clear_start_time(ui, time_id).is_some() {
+                mem::clear_clock(ui, time_id);
+            }
+            add_contents(ui, current_value);
+            AnimationResult::default()
+        }
         false => {
-            let start_time = mem::get_or_insert_start_time(ui, id, current_time);
-            let animation = AnimationState::new(start_time, current_time, animation);
+            let was_running = mem::get_start_time(ui, time_id).is_some();
+            if !was_running {
+                // Discard the durations of a partially cleared prior animation.
+                mem::clear_clock(ui, time_id);
+            }
+            let start_time = match mem::get_or_insert_start_time(ui, time_id, current_time) {
+                // Restart animations started ahead of the current time (i.e. a prior session).
+                start_time if start_time > current_time => {
+                    mem::insert_start_time(ui, time_id, current_time)
+                }
+                start_time => start_time,
+            };
+            let animation = animation(&start_value, &current_value);
+            // Keep the durations of the animation at the time it started.
+            let clock = mem::get_or_insert_clock(ui, time_id, || {
+                source.clock(start_time, current_time, &animation)
+            });
+            let clock = source.frame_clock(ui.ctx(), clock, start_time, current_time);
+            let caught_up = source == TimeSource::Seconds && over_frame_budget(ui.ctx());
+            let dt = ui.ctx().input(|input| input.stable_dt);
+            mem::update_stats(ui, id, |stats| {
+                if !was_running {
+                    *stats = AnimationStats::default();
+                }
+                stats.sample(dt, was_running, caught_up);
+            });
+            let animation = AnimationState::from_clock(clock, animation);
+            let run_state = animation.run_state();
+            // Running animations scrolled out of view skip their transforms and repaints.
+            let off_screen = run_state.is_running()
+                && mem::get_rect(ui, id).is_some_and(|rect| !ui.clip_rect().intersects(rect));
+
+            let repaint_after = match (source, off_screen) {
+                // Frames advance only with repaints, so repaint every frame while running.
+                (TimeSource::Frames { .. }, _) => run_state.is_running().then_some(0.0),
+                // Repaint once finished, in case the animation is never scrolled into view.
+                (TimeSource::Seconds, true) => Some(clock.in_end() - current_time),
+                // Repaint once the animation changes, and no sooner than the next frame step.
+                (TimeSource::Seconds, false) => {
+                    Some(animation.next_frame_after(ui.ctx(), current_time))
+                }
+            };
+            if let Some(repaint_after) = repaint_after {
+                request_repaint_after(ui.ctx(), repaint_after);
+            }
+            match run_state.is_running() {
+                true => {
+                    ui.ctx().add_plugin(SettlePlugin);
+                    mem::register_animation::<T>(ui.ctx(), id);
+                    mem::insert_target_value(ui, id, current_value.clone());
+                    on_frame(run_state.clone());
+                }
+                false => mem::unregister_animation(ui.ctx(), id),
+            }
 
-            ui.ctx().request_repaint();
-            animation.animate(ui, id, start_value, current_value, add_contents)
+            let mut rect = egui::Rect::NOTHING;
+            let mut add_contents = |ui: &mut egui::Ui, value: T| {
+                let inner = add_contents(ui, value);
+                rect = rect.union(ui.min_rect());
+                inner
+            };
+            match off_screen {
+                true => {
+                    mem::clear_animation_layer(ui, id);
+                    mem::clear_animation_layer(ui, id.with(OVERLAP_SUFFIX));
+                    ui.scope(|ui| add_contents(ui, current_value));
+                }
+                false => {
+                    animation.animate(ui, id, start_value, current_value, add_contents);
+                }
+            }
+            if run_state.is_running() {
+                mem::insert_rect(ui, id, rect);
+            }
+
+            AnimationResult {
+                just_started: !was_running,
+                just_finished: !run_state.is_running(),
+                run_state,
+            }
         }
-    };
+    }
+}
+
+/// Create an animation that transitions between changes of the given `value`, progressing
+/// by frame counts rather than time. See [`animate`].
+///
+/// The *out* and *in* segments last `out_frames` and `in_frames` frames, counted with
+/// [`egui::Context::cumulative_frame_nr`], and the durations, overlap and hold of the
+/// given `animation` are ignored. The same sequence of frames always renders the same
+/// *normals*, for reproducible snapshot tests of animations in headless runs. A repaint
+/// is requested every frame while running, and the frame rate limit and frame budget do
+/// not apply.
+///
+/// Otherwise, frame-driven animations behave as with [`animate`]: reverted values play
+/// backward, retriggers restart, and removed animations settle. The frame counts are kept
+/// apart from start times in seconds, so the [`RunState`] of frame-driven animations is
+/// not available from [`run_state`].
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # const FADE_ANIM: Animation = Animation::EMPTY;
+/// # let mut my_state: u32 = 0;
+/// #
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// // Fade out over 4 frames, then in over 4 frames.
+/// animate_by_frames(ui, "my_fade", my_state, 4, 4, FADE_ANIM, |ui, value| {
+///     ui.label(format!("Value is {}", value));
+/// });
+/// #
+/// # });
+/// # });
+/// ```
+pub fn animate_by_frames<T: 'static + Any + Clone + Send + Sync + Default + PartialEq, R>(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    value: T,
+    out_frames: u32,
+    in_frames: u32,
+    animation: impl Into<Animation>,
+    add_contents: impl FnOnce(&mut egui::Ui, T) -> R,
+) -> AnimationResult {
+    let animation = animation.into();
+    animate_with_source(
+        ui,
+        id.into(),
+        value,
+        TimeSource::Frames {
+            out_frames,
+            in_frames,
+        },
+        |_, _| animation,
+        add_contents,
+        |_| {},
+    )
+}
+
+/// Apply the given animation function with a caller-provided `normal`, scoping all
+/// `egui::Ui` mutations within `add_contents`.
+///
+/// Unlike [`animate`], no value is tracked and no *out*/*in* segments are run. Useful
+/// for animations driven by external progress, such as a loading percentage. Disabled
+/// animations (see [`set_animation_enabled`](crate::set_animation_enabled)) add the
+/// contents without the animation function.
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// // The externally computed progress.
+/// let progress: f32 = 0.25;
+///
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// animate_progress(ui, "my_progress", progress, |ui, normal| ui.set_opacity(normal), |ui| {
+///     ui.label("Loading...");
+/// });
+/// #
+/// # });
+/// # });
+/// ```
+pub fn animate_progress<R>(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    normal: f32,
+    anim_fn: fn(&mut egui::Ui, f32),
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> R {
+    let id: egui::Id = id.into();
+
+    if !animation_enabled(ui.ctx(), id) {
+        mem::clear_animation_layer(ui, id);
+        return ui.scope(add_contents).inner;
+    }
+    AnimationSegment::new(0.0, anim_fn).animate(ui, id, normal, add_contents)
 }
 
 /// Get the [`RunState`] for the animation of the given `id`. Returns `RunState::None`
-/// for animations that do not exist.
+/// for animations that do not exist. The *normal* is the raw progress of the segment,
+/// before easing. See [`run_state_eased`].
 ///
 /// # Example
 /// ```
@@ -92,98 +539,526 @@ pub fn animate<T: 'static + Any + Clone + Send + Sync + Default + PartialEq, R>(
 /// # });
 /// # });
 /// ```
-pub fn run_state(ui: &mut egui::Ui, id: impl Into<egui::Id>, animation: Animation) -> RunState {
-    let id: egui::Id = id.into();
+pub fn run_state(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    animation: impl Into<Animation>,
+) -> RunState {
+    stored_state(ui, id.into(), animation.into())
+        .map_or_else(Default::default, |state| state.run_state())
+}
 
-    match mem::get_start_time(ui, id) {
-        Some(start_time) => {
-            let current_time = ui.ctx().input(|input| input.time);
-            AnimationState::new(start_time, current_time, animation).run_state()
-        }
-        None => Default::default(),
+/// Get the [`RunState`] for the animation of the given `id`, with the *normal* eased by
+/// the segment. Returns `RunState::None` for animations that do not exist.
+///
+/// The *normal* of [`run_state`] is the raw, linear progress of the segment. The eased
+/// *normal* is the value passed to the animation function, after the keyframes, remapping,
+/// default easing and clamping of the segment (see [`AnimationSegment::eased`] and
+/// [`AnimationSegment::with_default_easing`]). Use the eased run state
+/// to sync external visuals with the animation, and the raw run state for timing.
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # const MY_ANIM: Animation = Animation::EMPTY;
+/// #
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// // Follow the eased opacity of the animation.
+/// let presence = run_state_eased(ui, "my_anim", MY_ANIM).presence();
+/// #
+/// # });
+/// # });
+/// ```
+pub fn run_state_eased(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    animation: impl Into<Animation>,
+) -> RunState {
+    let animation = animation.into();
+    match run_state(ui, id, animation) {
+        RunState::OutSeg(normal) => RunState::OutSeg(animation.out_seg.eased_in(ui.ctx(), normal)),
+        RunState::InSeg(normal) => RunState::InSeg(animation.in_seg.eased_in(ui.ctx(), normal)),
+        run_state => run_state,
     }
 }
 
-/// The current state of an animation. Defines an animation scope, delegating variables
-/// to the currently progressing animation.
-struct AnimationState {
+/// Get a single value driving the animation of the given `id`, for composing manual
+/// effects outside of the [`animate`] scope.
+///
+/// The driver is the complement of [`RunState::presence`], from the raw *normal* of the
+/// current segment:
+///
+/// [`RunState`] | Driver
+/// ---|---
+/// `None` | `0.0`, at rest.
+/// `OutSeg(normal)` | `normal`, rising from `0.0` to `1.0`.
+/// `Hold(_)` | `1.0`.
+/// `InSeg(normal)` | `1.0 - normal`, falling from `1.0` to `0.0`.
+///
+/// The driver is clamped to `0.0..=1.0`.
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # const MY_ANIM: Animation = Animation::EMPTY;
+/// # let my_state: u32 = 0;
+/// #
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// animate(ui, "my_anim", my_state, MY_ANIM, |ui, value| {
+///     // ...
+/// });
+///
+/// // Dim a sibling label while transitioning.
+/// let dim = driver(ui, "my_anim", MY_ANIM);
+/// ui.scope(|ui| {
+///     ui.multiply_opacity(1.0 - 0.5 * dim);
+///     ui.label("Details");
+/// });
+/// #
+/// # });
+/// # });
+/// ```
+pub fn driver(ui: &mut egui::Ui, id: impl Into<egui::Id>, animation: impl Into<Animation>) -> f32 {
+    (1.0 - run_state(ui, id, animation).presence()).clamp(0.0, 1.0)
+}
+
+/// Get the time until the animation of the given `id` next requires a repaint. Returns
+/// `None` if the animation is not running.
+///
+/// Matches the repaint requested by [`animate`], returning [`Duration::ZERO`] while the
+/// animation changes every frame. Useful for integrating animations with the scheduling
+/// of power-conscious apps.
+///
+/// [`Duration::ZERO`]: std::time::Duration::ZERO
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # const MY_ANIM: Animation = Animation::EMPTY;
+/// #
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// if let Some(repaint_after) = next_repaint_after(ui, "my_anim", MY_ANIM) {
+///     println!("Next frame in {repaint_after:?}");
+/// }
+/// #
+/// # });
+/// # });
+/// ```
+pub fn next_repaint_after(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    animation: impl Into<Animation>,
+) -> Option<std::time::Duration> {
+    let state = stored_state(ui, id.into(), animation.into())?;
+    if !state.run_state().is_running() {
+        return None;
+    }
+
+    let current_time = ui.ctx().input(|input| input.time);
+    let repaint_after = state.next_frame_after(ui.ctx(), current_time);
+    Some(std::time::Duration::from_secs_f64(repaint_after))
+}
+
+/// Get the prior value of the running animation of the given `id`, from before the
+/// value changed. Returns `None` if the animation is not running, or the value is not of
+/// type `T`.
+///
+/// Useful for describing the change during a transition, e.g. "changed from X to Y".
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # const MY_ANIM: Animation = Animation::EMPTY;
+/// # let my_state: u32 = 0;
+/// #
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// animate(ui, "my_anim", my_state, MY_ANIM, |ui, value| {
+///     ui.label(value.to_string());
+/// });
+///
+/// if let Some(previous) = previous_value::<u32>(ui, "my_anim") {
+///     ui.label(format!("Changed from {previous} to {my_state}"));
+/// }
+/// #
+/// # });
+/// # });
+/// ```
+pub fn previous_value<T: 'static + Any + Clone + Send + Sync>(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+) -> Option<T> {
+    let id = id.into();
+    mem::get_start_time(ui, id)?;
+    mem::get_start_value(ui, id)
+}
+
+/// Get the [`AnimationState`] of the animation of the given `id` for the current frame.
+/// Returns `None` for animations that have not started.
+fn stored_state(ui: &mut egui::Ui, id: egui::Id, animation: Animation) -> Option<AnimationState> {
+    let start_time = mem::get_start_time(ui, id)?;
+    let current_time = ui.ctx().input(|input| input.time);
+    let mut state = match mem::get_clock(ui, id) {
+        Some(clock) => AnimationState::from_clock(clock, animation),
+        None => AnimationState::new(start_time, current_time, animation),
+    };
+    state.clock = frame_clock(ui.ctx(), state.clock, start_time, current_time);
+    Some(state)
+}
+
+/// Get the clock of the animation started at the given `start_time` for the current
+/// frame, keeping the durations of the given `clock`.
+///
+/// The current time is stepped by the frame rate limit (see [`frame_time`]), and snapped
+/// to the end of the animation on frames over the frame budget (see
+/// [`set_frame_budget`](crate::set_frame_budget)).
+fn frame_clock(
+    ctx: &egui::Context,
+    clock: AnimationClock,
     start_time: f64,
     current_time: f64,
+) -> AnimationClock {
+    let mut clock = AnimationClock {
+        start_time,
+        current_time: frame_time(ctx, start_time, current_time),
+        ..clock
+    };
+    if over_frame_budget(ctx) {
+        clock.current_time = clock.current_time.max(clock.in_end());
+    }
+    clock
+}
 
-    animation: Animation,
+/// Get the ids of all currently running animations, in no particular order.
+///
+/// Animations not updated during a frame, such as those of removed widgets, are settled
+/// at the end of the frame and no longer considered running.
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// # let ctx = egui::Context::default();
+/// for id in running_animations(&ctx) {
+///     println!("Animation {id:?} is running");
+/// }
+/// ```
+pub fn running_animations(ctx: &egui::Context) -> Vec<egui::Id> {
+    mem::registered_animations(ctx)
 }
 
-impl AnimationState {
-    /// Create a new `AnimationState` from the `start_time`, `current_time` and `Animation`.
-    pub const fn new(start_time: f64, current_time: f64, animation: Animation) -> Self {
-        Self {
-            start_time,
-            current_time,
-            animation,
-        }
+/// Returns `true` if any animation is currently running. See [`running_animations`].
+pub fn any_animation_running(ctx: &egui::Context) -> bool {
+    !running_animations(ctx).is_empty()
+}
+
+/// Add the given `widget`, disabled while the animation of the given `id` is running.
+///
+/// The widget is disabled for both the *out* and *in* segments, preventing changes to
+/// the animated value mid-transition.
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # const MY_ANIM: Animation = Animation::EMPTY;
+/// # let mut my_state: u32 = 0;
+/// #
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// let button = egui::Button::new("Increment u32");
+/// if lock_during_animation(ui, "my_anim", MY_ANIM, button).clicked() {
+///     my_state += 1;
+/// }
+///
+/// animate(ui, "my_anim", my_state, MY_ANIM, |ui, value| {
+///     // ...
+/// });
+/// #
+/// # });
+/// # });
+/// ```
+pub fn lock_during_animation(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    animation: impl Into<Animation>,
+    widget: impl egui::Widget,
+) -> egui::Response {
+    let enabled = !run_state(ui, id, animation).is_running();
+    ui.add_enabled(enabled, widget)
+}
+
+/// Add the given `add_contents`, disabled while the animation of the given `id` is
+/// running. See [`lock_during_animation`] for a single widget.
+///
+/// Disabling the controls that change the animated value for both the *out* and *in*
+/// segments prevents re-triggering the animation mid-transition.
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # const MY_ANIM: Animation = Animation::EMPTY;
+/// # let mut my_state: i32 = 0;
+/// #
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// disabled_while_animating(ui, "my_anim", MY_ANIM, |ui| {
+///     if ui.button("-").clicked() {
+///         my_state -= 1;
+///     }
+///     if ui.button("+").clicked() {
+///         my_state += 1;
+///     }
+/// });
+///
+/// animate(ui, "my_anim", my_state, MY_ANIM, |ui, value| {
+///     // ...
+/// });
+/// #
+/// # });
+/// # });
+/// ```
+pub fn disabled_while_animating<R>(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    animation: impl Into<Animation>,
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> egui::InnerResponse<R> {
+    let enabled = !run_state(ui, id, animation).is_running();
+    ui.add_enabled_ui(enabled, add_contents)
+}
+
+/// Replay the animation of the given `id` from the start, even if the animated value is
+/// unchanged, such as flashing on re-submitting the same value. Both segments animate the
+/// current value. Restarts the animation if already running.
+///
+/// Must be called before the [`animate`] call of the animation for the current frame.
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # const FLASH_ANIM: Animation = Animation::EMPTY;
+/// # let my_state: u32 = 0;
+/// #
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// if ui.button("Submit").clicked() {
+///     retrigger_animation(ui, "my_flash");
+/// }
+///
+/// animate(ui, "my_flash", my_state, FLASH_ANIM, |ui, value| {
+///     ui.label(format!("Submitted {value}"));
+/// });
+/// #
+/// # });
+/// # });
+/// ```
+pub fn retrigger_animation(ui: &mut egui::Ui, id: impl Into<egui::Id>) {
+    let id: egui::Id = id.into();
+
+    mem::clear_start_time(ui, id);
+    mem::clear_clock(ui, id);
+    mem::insert_retrigger(ui, id);
+}
+
+/// Settles running animations not updated during a pass, such as those of removed
+/// widgets. Clears the memory and layer transforms so the animation does not resume, or
+/// leave a transform applied to a reused layer.
+struct SettlePlugin;
+
+impl egui::Plugin for SettlePlugin {
+    fn debug_name(&self) -> &'static str {
+        "egui_animate::SettlePlugin"
     }
 
-    /// Get the **out** segment duration.
-    #[inline]
-    fn out_dur(&self) -> f32 {
-        self.animation.out_seg.duration
+    fn on_end_pass(&mut self, ctx: &egui::Context) {
+        for id in mem::settle_animations(ctx, ctx.cumulative_pass_nr()) {
+            mem::clear_animation_layers(ctx, id);
+            mem::clear_animation_layers(ctx, id.with(OVERLAP_SUFFIX));
+        }
     }
+}
 
-    /// Get the **out** segment start time.
-    #[inline]
-    fn out_start(&self) -> f64 {
-        self.start_time
+/// Clear the memory of the animation of the given `id`, and the transforms of its layers.
+///
+/// Kept for reading once the animation finishes are its frame statistics (see
+/// [`animation_stats`](crate::animation_stats)), its mark (see
+/// [`animate_at`](crate::animate_at)), and the observations of [`after`](crate::after),
+/// which belong to the observers.
+fn clear_animation<T: 'static + Any + Clone + Send + Sync + Default>(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+) {
+    mem::clear_start_value::<T>(ui, id);
+    mem::clear_target_value::<T>(ui, id);
+    mem::clear_start_time(ui, id);
+    mem::clear_clock(ui, id);
+    mem::clear_start_time(ui, mem::frames_id(id));
+    mem::clear_clock(ui, mem::frames_id(id));
+    mem::clear_retrigger(ui, id);
+    mem::clear_rect(ui, id);
+    mem::clear_data(ui.ctx(), id);
+    mem::clear_discarded(ui.ctx(), id);
+    mem::clear_animation_layer(ui, id);
+    mem::clear_animation_layer(ui, id.with(OVERLAP_SUFFIX));
+}
+
+/// Show the current value of the disabled animation of the given `id`, clearing any
+/// in-flight state. See [`set_animation_enabled`](crate::set_animation_enabled).
+fn animate_disabled<T: 'static + Any + Clone + Send + Sync + Default, R>(
+    ui: &mut egui::Ui,
+    id: egui::Id,
+    current_value: T,
+    add_contents: impl FnOnce(&mut egui::Ui, T) -> R,
+) -> AnimationResult {
+    let was_running = mem::get_start_time(ui, id).is_some();
+    clear_animation::<T>(ui, id);
+    mem::clear_mark(ui, id);
+    mem::clear_stats(ui, id);
+    mem::unregister_animation(ui.ctx(), id);
+    add_contents(ui, current_value);
+
+    AnimationResult {
+        just_finished: was_running,
+        ..Default::default()
     }
+}
 
-    /// Get the **out** segment end time.
-    #[inline]
-    fn out_end(&self) -> f64 {
-        self.out_start() + self.out_dur() as f64
+/// Wrap the `add_contents` of a running animation for the given `run_state`, with
+/// debug drawing and accessibility.
+fn wrap_running<R>(
+    run_state: RunState,
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> impl FnOnce(&mut egui::Ui) -> R {
+    with_accessibility(with_debug_draw(run_state, add_contents))
+}
+
+/// The source of time counted by the clock of an animation.
+#[derive(Clone, Copy, PartialEq)]
+enum TimeSource {
+    /// Seconds of [`egui::InputState::time`].
+    Seconds,
+    /// Frames of [`egui::Context::cumulative_frame_nr`], lasting the given frame counts per
+    /// segment.
+    Frames { out_frames: u32, in_frames: u32 },
+}
+
+impl TimeSource {
+    /// Get the current time of the source.
+    fn current_time(self, ctx: &egui::Context) -> f64 {
+        match self {
+            TimeSource::Seconds => ctx.input(|input| input.time),
+            TimeSource::Frames { .. } => ctx.cumulative_frame_nr() as f64,
+        }
     }
 
-    /// Get the elapsed time of the **out** segment. Returns `Some(0.0)` if the animation
-    /// has yet to begin, and `None` if the animation has finished.
-    fn out_elapsed(&self) -> Option<f32> {
-        let out_elapsed = (self.current_time - self.out_start()).max(0.0) as f32;
-        (out_elapsed < self.out_dur()).then_some(out_elapsed)
+    /// Get the id keeping the start time and clock of the animation of the given `id`.
+    /// Frame counts are kept apart from the start times in seconds read by
+    /// [`run_state`] and others.
+    fn time_id(self, id: egui::Id) -> egui::Id {
+        match self {
+            TimeSource::Seconds => id,
+            TimeSource::Frames { .. } => mem::frames_id(id),
+        }
     }
 
-    /// Get the elapsed normal of the **out** segment. Returns `Some(0.0)` if the animation
-    /// has yet to begin, and `None` if the animation has finished.
-    fn out_elapsed_normal(&self) -> Option<f32> {
-        self.out_elapsed().map(|elapsed| elapsed / self.out_dur())
+    /// Create the clock of the given `animation` started at the `start_time`. Frame
+    /// clocks ignore the durations, overlap and hold of the animation.
+    fn clock(self, start_time: f64, current_time: f64, animation: &Animation) -> AnimationClock {
+        match self {
+            TimeSource::Seconds => {
+                AnimationClock::from_animation(start_time, current_time, animation)
+            }
+            TimeSource::Frames {
+                out_frames,
+                in_frames,
+            } => AnimationClock::new(
+                start_time,
+                current_time,
+                out_frames as f32,
+                in_frames as f32,
+            ),
+        }
     }
 
-    /// Get the **in** segment duration.
-    #[inline]
-    fn in_dur(&self) -> f32 {
-        self.animation.in_seg.duration
+    /// Get the clock for the current frame. Frame clocks are neither stepped by the frame
+    /// rate limit nor snapped by the frame budget, so they stay reproducible.
+    fn frame_clock(
+        self,
+        ctx: &egui::Context,
+        clock: AnimationClock,
+        start_time: f64,
+        current_time: f64,
+    ) -> AnimationClock {
+        match self {
+            TimeSource::Seconds => frame_clock(ctx, clock, start_time, current_time),
+            TimeSource::Frames { .. } => AnimationClock {
+                start_time,
+                current_time,
+                ..clock
+            },
+        }
     }
+}
 
-    /// Get the **in** segment start time.
-    #[inline]
-    fn in_start(&self) -> f64 {
-        self.out_end()
-    }
+/// The current state of an animation. Defines an animation scope, delegating variables
+/// to the currently progressing animation.
+struct AnimationState {
+    clock: AnimationClock,
 
-    /// Get the **in** segment end time.
-    #[allow(dead_code)]
-    #[inline]
-    fn in_end(&self) -> f64 {
-        self.in_start() + self.in_dur() as f64
-    }
+    animation: Animation,
+}
 
-    /// Get the elapsed time of the **in** segment. Returns `Some(0.0)` if the animation
-    /// has yet to begin, and `None` if the animation has finished.
-    fn in_elapsed(&self) -> Option<f32> {
-        let in_elapsed = (self.current_time - self.in_start()).max(0.0) as f32;
-        (in_elapsed < self.in_dur()).then_some(in_elapsed)
+impl AnimationState {
+    /// Create a new `AnimationState` from the `start_time`, `current_time` and `Animation`.
+    /// A `start_time` later than the `current_time` is treated as freshly started.
+    pub const fn new(start_time: f64, current_time: f64, animation: Animation) -> Self {
+        Self {
+            clock: AnimationClock::from_animation(start_time, current_time, &animation),
+            animation,
+        }
     }
 
-    /// Get the elapsed normal of the **in** segment. Returns `Some(0.0)` if the animation
-    /// has yet to begin, and `None` if the animation has finished.
-    fn in_elapsed_normal(&self) -> Option<f32> {
-        self.in_elapsed().map(|elapsed| elapsed / self.in_dur())
+    /// Create a new `AnimationState` from the given `clock` and `Animation`. The durations
+    /// of the `clock` take precedence over those of the `animation`.
+    pub const fn from_clock(clock: AnimationClock, animation: Animation) -> Self {
+        Self { clock, animation }
     }
 
     /// Call the `AnimationSegment` for the current frame.
@@ -193,21 +1068,31 @@ impl AnimationState {
         id: egui::Id,
         start_value: T,
         current_value: T,
-        add_contents: impl FnOnce(&mut egui::Ui, T) -> R,
+        mut add_contents: impl FnMut(&mut egui::Ui, T) -> R,
     ) -> R {
         match self.run_state() {
-            RunState::OutSeg(normal) => {
-                self.animate_out(ui, id, normal, |ui| add_contents(ui, start_value))
+            RunState::OutSeg(normal) => match self.clock.overlap_in_normal() {
+                Some(in_normal) => self.animate_overlap(
+                    ui,
+                    id,
+                    (normal, in_normal),
+                    (start_value, current_value),
+                    add_contents,
+                ),
+                None => self.animate_out(ui, id, normal, |ui| add_contents(ui, start_value)),
+            },
+            RunState::Hold(normal) => {
+                let add_contents =
+                    wrap_running(RunState::Hold(normal), |ui| add_contents(ui, start_value));
+                self.animation.out_seg.animate(ui, id, 1.0, add_contents)
             }
             RunState::InSeg(normal) => {
                 mem::clear_animation_layer(ui, id);
+                mem::clear_animation_layer(ui, id.with(OVERLAP_SUFFIX));
                 self.animate_in(ui, id, normal, |ui| add_contents(ui, current_value))
             }
             RunState::None => {
-                mem::clear_start_value::<T>(ui, id);
-                mem::clear_start_time(ui, id);
-                mem::clear_animation_layer(ui, id);
-
+                clear_animation::<T>(ui, id);
                 add_contents(ui, current_value)
             }
         }
@@ -222,6 +1107,7 @@ impl AnimationState {
         normal: f32,
         add_contents: impl FnOnce(&mut egui::Ui) -> R,
     ) -> R {
+        let add_contents = wrap_running(RunState::OutSeg(normal), add_contents);
         self.animation.out_seg.animate(ui, id, normal, add_contents)
     }
 
@@ -234,26 +1120,119 @@ impl AnimationState {
         normal: f32,
         add_contents: impl FnOnce(&mut egui::Ui) -> R,
     ) -> R {
+        let add_contents = wrap_running(RunState::InSeg(normal), add_contents);
         self.animation.in_seg.animate(ui, id, normal, add_contents)
     }
 
+    /// Render both the **out** and **in** segments over the same region, each on a
+    /// separate layer. The **in** segment is drawn above the **out** segment.
+    fn animate_overlap<T, R>(
+        &self,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        (out_normal, in_normal): (f32, f32),
+        (start_value, current_value): (T, T),
+        mut add_contents: impl FnMut(&mut egui::Ui, T) -> R,
+    ) -> R {
+        let rect = ui.available_rect_before_wrap();
+
+        let add_out = wrap_running(RunState::OutSeg(out_normal), |ui| {
+            add_contents(ui, start_value)
+        });
+        let (_, out_rect) = self
+            .animation
+            .out_seg
+            .animate_overlay(ui, id, id, rect, out_normal, add_out);
+
+        let add_in = wrap_running(RunState::InSeg(in_normal), |ui| {
+            add_contents(ui, current_value)
+        });
+        let overlap_id = id.with(OVERLAP_SUFFIX);
+        let (inner, in_rect) = self
+            .animation
+            .in_seg
+            .animate_overlay(ui, overlap_id, id, rect, in_normal, add_in);
+
+        ui.advance_cursor_after_rect(out_rect.union(in_rect));
+
+        inner
+    }
+
+    /// Get the time (in seconds) until the animation next requires a repaint. Returns
+    /// `0.0` while the animation changes every frame.
+    fn repaint_after(&self) -> f64 {
+        let static_until = match self.run_state() {
+            RunState::OutSeg(_) if self.clock.overlap_in_normal().is_some() => None,
+            RunState::OutSeg(normal) => (self.animation.out_seg.static_until(normal))
+                .map(|until| self.clock.out_start() + until as f64 * self.clock.out_dur as f64),
+            RunState::Hold(_) => Some(self.clock.in_start()),
+            RunState::InSeg(normal) => (self.animation.in_seg.static_until(normal))
+                .map(|until| self.clock.in_start() + until as f64 * self.clock.in_dur as f64),
+            RunState::None => None,
+        };
+        static_until.map_or(0.0, |time| (time - self.clock.current_time).max(0.0))
+    }
+
+    /// Get the time (in seconds) from the given `current_time` until the animation next
+    /// requires a repaint, no sooner than the next frame step.
+    fn next_frame_after(&self, ctx: &egui::Context, current_time: f64) -> f64 {
+        let repaint_after = self.repaint_after().max(frame_interval(ctx));
+        (repaint_after + self.clock.current_time - current_time).max(0.0)
+    }
+
     /// Get the `RunState` for the current frame.
+    #[inline]
     fn run_state(&self) -> RunState {
-        if let Some(normal) = self.out_elapsed_normal() {
-            RunState::OutSeg(normal)
-        } else if let Some(normal) = self.in_elapsed_normal() {
-            RunState::InSeg(normal)
-        } else {
-            RunState::None
-        }
+        self.clock.run_state()
     }
 }
 
+/// The result of an [`animate`] call for the current frame.
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # const MY_ANIM: Animation = Animation::EMPTY;
+/// # let mut my_state: u32 = 0;
+/// #
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// let result = animate(ui, "my_anim", my_state, MY_ANIM, |ui, value| {
+///     // ...
+/// });
+///
+/// if result.just_finished {
+///     println!("Animation finished");
+/// }
+/// #
+/// # });
+/// # });
+/// ```
+#[derive(Debug, Default, PartialEq)]
+pub struct AnimationResult {
+    /// The [`RunState`] of the animation for the current frame.
+    pub run_state: RunState,
+    /// `true` if the animation started on the current frame.
+    pub just_started: bool,
+    /// `true` if the animation finished on the current frame.
+    pub just_finished: bool,
+}
+
 /// An identified animation segment and *normal*.
-#[derive(Debug, Default, PartialEq, PartialOrd)]
+///
+/// Serializable with the `serde` feature, for logging the state of animations over time.
+#[derive(Debug, Default, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RunState {
     /// The *out* animation segment normal.
     OutSeg(f32),
+    /// The normal of the hold between the *out* and *in* segments.
+    Hold(f32),
     /// The *in* animation segment normal.
     InSeg(f32),
     /// The animation is not currently running.
@@ -262,101 +1241,1064 @@ pub enum RunState {
 }
 
 impl RunState {
-    /// Returns `true` if the animation is in either the *out* or *in* state.
+    /// Returns `true` if the animation is in the *out*, hold or *in* state.
+    ///
+    /// Prefer this over matching a single segment when disabling input during an
+    /// animation. Input accepted during the *out* segment changes the value mid-transition,
+    /// causing the animation to jump. See [`lock_during_animation`].
     pub fn is_running(&self) -> bool {
         match self {
-            RunState::OutSeg(_) | RunState::InSeg(_) => true,
+            RunState::OutSeg(_) | RunState::Hold(_) | RunState::InSeg(_) => true,
             RunState::None => false,
         }
     }
+
+    /// Returns `true` if the animation is in the *out* state.
+    pub fn is_out_seg(&self) -> bool {
+        matches!(self, RunState::OutSeg(_))
+    }
+
+    /// Returns `true` if the animation is holding between the *out* and *in* states.
+    pub fn is_hold(&self) -> bool {
+        matches!(self, RunState::Hold(_))
+    }
+
+    /// Returns `true` if the animation is in the *in* state.
+    pub fn is_in_seg(&self) -> bool {
+        matches!(self, RunState::InSeg(_))
+    }
+
+    /// Get the *normal* of the current segment. Returns `None` if the animation is not
+    /// running.
+    pub fn normal(&self) -> Option<f32> {
+        match self {
+            RunState::OutSeg(normal) | RunState::Hold(normal) | RunState::InSeg(normal) => {
+                Some(*normal)
+            }
+            RunState::None => None,
+        }
+    }
+
+    /// Get the inverse *normal* (`1.0 - normal`) of the *out* segment, progressing from
+    /// `1.0` to `0.0`. Returns `None` outside of the *out* segment.
+    pub fn out_inverse(&self) -> Option<f32> {
+        match self {
+            RunState::OutSeg(normal) => Some(1.0 - normal),
+            _ => None,
+        }
+    }
+
+    /// Get the presence of the animated contents, from `0.0` (hidden) to `1.0` (shown).
+    ///
+    /// Follows the canonical direction of animation functions: the *out* segment
+    /// progresses from shown to hidden, and the *in* segment from hidden to shown.
+    /// Returns `0.0` while holding, and `1.0` if the animation is not running.
+    pub fn presence(&self) -> f32 {
+        match self {
+            RunState::OutSeg(normal) => 1.0 - normal,
+            RunState::Hold(_) => 0.0,
+            RunState::InSeg(normal) => *normal,
+            RunState::None => 1.0,
+        }
+    }
+
+    /// Get the signed progress of the animation, crossing `0.0` between segments. Returns
+    /// `None` if the animation is not running.
+    ///
+    /// The *out* segment maps from `-1.0` (at normal `0.0`) towards `0.0`, holding maps to
+    /// `0.0`, and the *in* segment maps from `0.0` towards `1.0` (at normal `1.0`). Useful
+    /// for a single continuous driver, such as a knob swinging through center.
+    pub fn signed_progress(&self) -> Option<f32> {
+        match self {
+            RunState::OutSeg(normal) => Some(normal - 1.0),
+            RunState::Hold(_) => Some(0.0),
+            RunState::InSeg(normal) => Some(*normal),
+            RunState::None => None,
+        }
+    }
+
+    /// Get the overall progress of the given `animation`, from `0.0` to `1.0` across both
+    /// segments and the hold. Returns `None` if the animation is not running, and `0.0`
+    /// for animations without duration.
+    pub fn progress(&self, animation: &Animation) -> Option<f32> {
+        let duration = animation.duration();
+        let elapsed = match self {
+            RunState::OutSeg(normal) => normal * animation.out_seg.duration,
+            RunState::Hold(normal) => animation.out_seg.duration + normal * animation.hold_dur(),
+            RunState::InSeg(normal) => {
+                animation.out_seg.duration + animation.hold_dur() - animation.overlap()
+                    + normal * animation.in_seg.duration
+            }
+            RunState::None => return None,
+        };
+        match duration > 0.0 {
+            true => Some((elapsed / duration).clamp(0.0, 1.0)),
+            false => Some(0.0),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    mod animation_state {
+    /// Run a single frame at the given `time`, returning the result of `animate`.
+    fn run_frame<T: 'static + Any + Clone + Send + Sync + Default + PartialEq>(
+        ctx: &egui::Context,
+        time: f64,
+        value: T,
+        animation: Animation,
+    ) -> AnimationResult {
+        let mut result = AnimationResult::default();
+        let input = egui::RawInput {
+            time: Some(time),
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                result = animate(ui, "test_anim", value.clone(), animation, |_, _| {});
+            });
+        });
+        result
+    }
+
+    mod animate {
         use super::*;
 
-        const TEST_ANIM_STATE: AnimationState = AnimationState::new(
-            1.0,
-            1.0,
-            Animation {
-                out_seg: crate::AnimationSegment {
-                    duration: 1.5,
-                    anim_fn: |_, _| {},
-                },
-                in_seg: crate::AnimationSegment {
-                    duration: 1.5,
-                    anim_fn: |_, _| {},
-                },
-            },
-        );
+        const TEST_ANIM: Animation = Animation::new(1.0, |_, _| {}, |_, _| {});
 
         #[test]
-        fn test_out_end() {
-            let state = TEST_ANIM_STATE;
-            assert_eq!(state.out_end(), 2.5);
+        fn test_animation_result() {
+            let ctx = egui::Context::default();
+
+            assert_eq!(
+                run_frame(&ctx, 0.0, 0, TEST_ANIM),
+                AnimationResult::default()
+            );
+            let result = run_frame(&ctx, 1.0, 1, TEST_ANIM);
+            assert_eq!(running_animations(&ctx), vec![egui::Id::new("test_anim")]);
+            assert_eq!(result.run_state, RunState::OutSeg(0.0));
+            assert!(result.just_started && !result.just_finished);
+            let result = run_frame(&ctx, 1.75, 1, TEST_ANIM);
+            assert_eq!(result.run_state, RunState::InSeg(0.5));
+            assert!(!result.just_started && !result.just_finished);
+            let result = run_frame(&ctx, 2.0, 1, TEST_ANIM);
+            assert_eq!(result.run_state, RunState::None);
+            assert!(!result.just_started && result.just_finished);
+            assert!(!any_animation_running(&ctx));
+            assert_eq!(
+                run_frame(&ctx, 2.5, 1, TEST_ANIM),
+                AnimationResult::default()
+            );
         }
 
         #[test]
-        fn test_out_elapsed() {
-            let mut state = TEST_ANIM_STATE;
+        fn test_animate_salted() {
+            let ctx = egui::Context::default();
+            let mut ids = Vec::new();
+            let input = egui::RawInput {
+                time: Some(0.0),
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    for salt in ["first", "second"] {
+                        ui.push_id(salt, |ui| {
+                            ids.push(ui.id().with("test_anim"));
+                            animate_salted(ui, "test_anim", 0, TEST_ANIM, |_, _| {});
+                        });
+                    }
+                });
+            });
 
-            assert_eq!(state.out_elapsed(), Some(0.0));
-            state.current_time = 2.0;
-            assert_eq!(state.out_elapsed(), Some(1.0));
-            state.current_time = 3.0;
-            assert_eq!(state.out_elapsed(), None);
-            state.current_time = 4.0;
-            assert_eq!(state.out_elapsed(), None);
+            assert_ne!(ids[0], ids[1]);
+            for id in ids {
+                assert_eq!(
+                    ctx.memory(|m| m.data.get_temp::<i32>(id.with("start_value"))),
+                    Some(0)
+                );
+            }
         }
+
         #[test]
-        fn test_out_elapsed_normal() {
-            let mut state = TEST_ANIM_STATE;
+        fn test_animation_changed_mid_flight() {
+            const LONG_ANIM: Animation = Animation::new(4.0, |_, _| {}, |_, _| {});
+            let ctx = egui::Context::default();
 
-            assert_eq!(state.out_elapsed_normal(), Some(0.0));
-            state.current_time = 1.75;
-            assert_eq!(state.out_elapsed_normal(), Some(0.5));
-            state.current_time = 3.0;
-            assert_eq!(state.out_elapsed_normal(), None);
-            state.current_time = 4.0;
-            assert_eq!(state.out_elapsed_normal(), None);
+            run_frame(&ctx, 0.0, 0, TEST_ANIM);
+            run_frame(&ctx, 1.0, 1, TEST_ANIM);
+            let result = run_frame(&ctx, 1.25, 1, LONG_ANIM);
+            assert_eq!(result.run_state, RunState::OutSeg(0.5));
+            let result = run_frame(&ctx, 1.5, 1, LONG_ANIM);
+            assert_eq!(result.run_state, RunState::InSeg(0.0));
+            let result = run_frame(&ctx, 2.0, 1, LONG_ANIM);
+            assert_eq!(result.run_state, RunState::None);
+
+            // The next animation uses the durations of the animation it started with.
+            run_frame(&ctx, 2.5, 1, LONG_ANIM);
+            run_frame(&ctx, 3.0, 2, LONG_ANIM);
+            let result = run_frame(&ctx, 4.0, 2, TEST_ANIM);
+            assert_eq!(result.run_state, RunState::OutSeg(0.5));
         }
 
         #[test]
-        fn test_in_end() {
-            let state = TEST_ANIM_STATE;
-            assert_eq!(state.in_end(), 4.0);
+        fn test_reverse_on_revert() {
+            let ctx = egui::Context::default();
+
+            run_frame(&ctx, 0.0, 0, TEST_ANIM);
+            run_frame(&ctx, 1.0, 1, TEST_ANIM);
+            let result = run_frame(&ctx, 1.25, 0, TEST_ANIM);
+            assert_eq!(result.run_state, RunState::InSeg(0.5));
+            assert!(!result.just_started);
+            let result = run_frame(&ctx, 1.5, 0, TEST_ANIM);
+            assert!(result.just_finished);
+
+            run_frame(&ctx, 2.0, 0, TEST_ANIM);
+            run_frame(&ctx, 3.0, 1, TEST_ANIM);
+            let result = run_frame(&ctx, 3.75, 0, TEST_ANIM);
+            assert_eq!(result.run_state, RunState::OutSeg(0.5));
+            let result = run_frame(&ctx, 4.25, 0, TEST_ANIM);
+            assert_eq!(result.run_state, RunState::InSeg(0.5));
+            let result = run_frame(&ctx, 4.5, 0, TEST_ANIM);
+            assert!(result.just_finished);
         }
 
         #[test]
-        fn test_in_elapsed() {
-            let mut state = TEST_ANIM_STATE;
+        fn test_frame_budget() {
+            let ctx = egui::Context::default();
+            crate::set_frame_budget(&ctx, Some(0.1));
+
+            run_frame(&ctx, 0.0, 0, TEST_ANIM);
+            run_frame(&ctx, 0.95, 0, TEST_ANIM);
+            run_frame(&ctx, 1.0, 1, TEST_ANIM);
+            let result = run_frame(&ctx, 1.05, 1, TEST_ANIM);
+            assert_eq!(result.run_state, RunState::OutSeg(0.1));
+            let result = run_frame(&ctx, 1.5, 1, TEST_ANIM);
+            assert_eq!(result.run_state, RunState::None);
+            assert!(result.just_finished);
+        }
+
+        #[test]
+        fn test_frame_budget_run_state() {
+            let ctx = egui::Context::default();
+            let run_state_at = |time: f64| {
+                let input = egui::RawInput {
+                    time: Some(time),
+                    ..Default::default()
+                };
+                let mut state = RunState::None;
+                let _ = ctx.run(input, |ctx| {
+                    egui::CentralPanel::default().show(ctx, |ui| {
+                        state = crate::run_state(ui, "test_anim", TEST_ANIM);
+                        animate(ui, "test_anim", 1, TEST_ANIM, |_, _| {});
+                    });
+                });
+                state
+            };
+
+            crate::set_frame_budget(&ctx, Some(0.1));
+
+            run_frame(&ctx, 0.0, 0, TEST_ANIM);
+            run_frame(&ctx, 0.95, 0, TEST_ANIM);
+            run_frame(&ctx, 1.0, 1, TEST_ANIM);
+            assert_eq!(run_state_at(1.05), RunState::OutSeg(0.1));
+            assert_eq!(run_state_at(1.6), RunState::None);
+        }
+
+        #[test]
+        fn test_negative_duration() {
+            let ctx = egui::Context::default();
+            let animation = Animation::new(-1.0, |_, _| {}, |_, _| {});
+
+            run_frame(&ctx, 0.0, 0, animation);
+            let result = run_frame(&ctx, 1.0, 1, animation);
+            assert_eq!(result.run_state, RunState::None);
+            assert!(result.just_finished);
+            assert!(!any_animation_running(&ctx));
+        }
+
+        #[test]
+        fn test_hold() {
+            let ctx = egui::Context::default();
+            let animation = TEST_ANIM.with_hold(1.0);
+            assert_eq!(animation.hold_dur(), 1.0);
+            assert_eq!(TEST_ANIM.with_hold(-1.0).hold_dur(), 0.0);
+
+            run_frame(&ctx, 0.0, 0, animation);
+            assert_eq!(
+                run_frame(&ctx, 1.0, 1, animation).run_state,
+                RunState::OutSeg(0.0)
+            );
+            let result = run_frame(&ctx, 1.75, 1, animation);
+            assert_eq!(result.run_state, RunState::Hold(0.25));
+            assert!(!result.just_finished);
+            assert!(any_animation_running(&ctx));
+            assert_eq!(
+                run_frame(&ctx, 2.75, 1, animation).run_state,
+                RunState::InSeg(0.5)
+            );
+            assert_eq!(run_frame(&ctx, 3.0, 1, animation).run_state, RunState::None);
+        }
+
+        #[test]
+        fn test_settle_removed_animation() {
+            const SLIDE_ANIM: Animation = Animation::new_out(1.0, |ui, normal| {
+                let translation = egui::vec2(normal + 1.0, 0.0);
+                ui.ctx().set_transform_layer(
+                    ui.layer_id(),
+                    egui::emath::TSTransform::from_translation(translation),
+                );
+            });
+            let ctx = egui::Context::default();
+            let id = egui::Id::new("test_anim");
+            let has_transform =
+                |ctx: &egui::Context| ctx.memory(|m| m.to_global.keys().any(|l| l.id == id));
+
+            run_frame(&ctx, 0.0, 0, SLIDE_ANIM);
+            run_frame(&ctx, 0.25, 1, SLIDE_ANIM);
+            assert!(has_transform(&ctx));
+            assert!(any_animation_running(&ctx));
+
+            // Skip the animation for a frame.
+            let _ = ctx.run(Default::default(), |_| {});
+            assert!(!has_transform(&ctx));
+            assert!(!any_animation_running(&ctx));
+            assert_eq!(
+                ctx.memory(|m| m.data.get_temp::<f64>(id.with("start_time"))),
+                None
+            );
+        }
+
+        #[test]
+        #[cfg(debug_assertions)]
+        fn test_duplicate_id() {
+            let ctx = egui::Context::default();
+            let id = egui::Id::new("test_anim");
+
+            for _ in 0..2 {
+                let _ = ctx.run(Default::default(), |ctx| {
+                    egui::CentralPanel::default().show(ctx, |ui| {
+                        animate(ui, id, 0, TEST_ANIM, |_, _| {});
+                    });
+                    assert!(mem::mark_animated(ctx, id));
+                });
+            }
+        }
+
+        #[test]
+        fn test_retrigger_animation() {
+            let ctx = egui::Context::default();
+            let run_retrigger_frame = |time: f64| {
+                let mut result = AnimationResult::default();
+                let input = egui::RawInput {
+                    time: Some(time),
+                    ..Default::default()
+                };
+                let _ = ctx.run(input, |ctx| {
+                    egui::CentralPanel::default().show(ctx, |ui| {
+                        retrigger_animation(ui, "test_anim");
+                        result = animate(ui, "test_anim", 0, TEST_ANIM, |_, _| {});
+                    });
+                });
+                result
+            };
+
+            run_frame(&ctx, 0.0, 0, TEST_ANIM);
+            let result = run_retrigger_frame(1.0);
+            assert_eq!(result.run_state, RunState::OutSeg(0.0));
+            assert!(result.just_started);
+            let result = run_frame(&ctx, 1.75, 0, TEST_ANIM);
+            assert_eq!(result.run_state, RunState::InSeg(0.5));
+            let result = run_frame(&ctx, 2.0, 0, TEST_ANIM);
+            assert!(result.just_finished);
+            assert_eq!(
+                run_frame(&ctx, 2.5, 0, TEST_ANIM),
+                AnimationResult::default()
+            );
+            // Retrigger mid-flight restarts the animation.
+            run_retrigger_frame(3.0);
+            let result = run_retrigger_frame(3.25);
+            assert_eq!(result.run_state, RunState::OutSeg(0.0));
+        }
+
+        #[test]
+        fn test_on_frame() {
+            let ctx = egui::Context::default();
+            let run_frame = |time: f64, value: u32| {
+                let mut frame_state = None;
+                let input = egui::RawInput {
+                    time: Some(time),
+                    ..Default::default()
+                };
+                let _ = ctx.run(input, |ctx| {
+                    egui::CentralPanel::default().show(ctx, |ui| {
+                        animate_with_on_frame(
+                            ui,
+                            "test_anim",
+                            value,
+                            TEST_ANIM,
+                            |_, _| {},
+                            |run_state| frame_state = Some(run_state),
+                        );
+                    });
+                });
+                frame_state
+            };
+
+            assert_eq!(run_frame(0.0, 0), None);
+            assert_eq!(run_frame(1.0, 1), Some(RunState::OutSeg(0.0)));
+            assert_eq!(run_frame(1.75, 1), Some(RunState::InSeg(0.5)));
+            assert_eq!(run_frame(2.0, 1), None);
+        }
+
+        #[test]
+        fn test_max_fps() {
+            let ctx = egui::Context::default();
+            crate::set_max_fps(&ctx, Some(4.0));
+
+            run_frame(&ctx, 0.0, 0, TEST_ANIM);
+            let result = run_frame(&ctx, 1.0, 1, TEST_ANIM);
+            assert_eq!(result.run_state, RunState::OutSeg(0.0));
+            let result = run_frame(&ctx, 1.2, 1, TEST_ANIM);
+            assert_eq!(result.run_state, RunState::OutSeg(0.0));
+            let result = run_frame(&ctx, 1.3, 1, TEST_ANIM);
+            assert_eq!(result.run_state, RunState::OutSeg(0.5));
+            let result = run_frame(&ctx, 1.6, 1, TEST_ANIM);
+            assert_eq!(result.run_state, RunState::InSeg(0.0));
+            let result = run_frame(&ctx, 2.1, 1, TEST_ANIM);
+            assert_eq!(result.run_state, RunState::None);
+        }
+
+        #[test]
+        fn test_new_in_shows_new_value() {
+            const IN_ANIM: Animation = Animation::new_in(1.0, |_, _| {});
+            let ctx = egui::Context::default();
+            let run_frame = |time: f64, value: i32| {
+                let input = egui::RawInput {
+                    time: Some(time),
+                    ..Default::default()
+                };
+                let mut shown = Vec::new();
+                let _ = ctx.run(input, |ctx| {
+                    egui::CentralPanel::default().show(ctx, |ui| {
+                        animate(ui, "test_anim", value, IN_ANIM, |_, value| {
+                            shown.push(value)
+                        });
+                    });
+                });
+                shown
+            };
+
+            assert_eq!(run_frame(0.0, 0), vec![0]);
+            // The old value is never rendered without an out segment.
+            assert_eq!(run_frame(1.0, 1), vec![1]);
+            assert_eq!(run_frame(1.5, 1), vec![1]);
+            assert_eq!(run_frame(2.5, 1), vec![1]);
+        }
+
+        #[test]
+        fn test_animate_crossfade() {
+            const CROSSFADE: Animation = Animation::crossfade(1.0);
+            let ctx = egui::Context::default();
+            let run_frame = |time: f64, value: i32, crossfade: bool| {
+                let input = egui::RawInput {
+                    time: Some(time),
+                    ..Default::default()
+                };
+                let mut shown = Vec::new();
+                let _ = ctx.run(input, |ctx| {
+                    egui::CentralPanel::default().show(ctx, |ui| {
+                        let add_contents = |_: &mut egui::Ui, value| shown.push(value);
+                        match crossfade {
+                            true => {
+                                animate_crossfade(ui, "test_anim", value, CROSSFADE, add_contents)
+                            }
+                            false => animate(ui, "test_anim", value, CROSSFADE, add_contents),
+                        };
+                    });
+                });
+                shown
+            };
+
+            // Both values are added while overlapping.
+            assert_eq!(run_frame(0.0, 0, true), vec![0]);
+            assert_eq!(run_frame(1.0, 1, true), vec![0, 1]);
+            assert_eq!(run_frame(1.5, 1, true), vec![0, 1]);
+            assert_eq!(run_frame(3.0, 1, true), vec![1]);
+            // The segments of `animate` play in sequence, adding the contents once.
+            assert_eq!(run_frame(3.5, 1, false), vec![1]);
+            assert_eq!(run_frame(4.0, 2, false), vec![1]);
+            assert_eq!(run_frame(4.5, 2, false), vec![1]);
+            assert_eq!(run_frame(5.5, 2, false), vec![2]);
+        }
+
+        #[test]
+        fn test_animate_fn_once() {
+            let ctx = egui::Context::default();
+            let label = String::from("moved");
+            let _ = ctx.run(egui::RawInput::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    let label = label.clone();
+                    animate(ui, "test_anim", 0, TEST_ANIM, move |ui, _| ui.label(label));
+                });
+            });
+        }
+
+        #[test]
+        fn test_skip_off_screen() {
+            fn slide(ui: &mut egui::Ui, normal: f32) {
+                ui.ctx().set_transform_layer(
+                    ui.layer_id(),
+                    egui::emath::TSTransform::from_translation(egui::vec2(normal + 1.0, 0.0)),
+                );
+            }
+            const SLIDE_ANIM: Animation = Animation::new(1.0, slide, slide);
+            let ctx = egui::Context::default();
+            let id = egui::Id::new("test_anim");
+            let has_transform =
+                |ctx: &egui::Context| ctx.memory(|m| m.to_global.keys().any(|l| l.id == id));
+            let run_frame = |time: f64, value: i32| {
+                let input = egui::RawInput {
+                    time: Some(time),
+                    ..Default::default()
+                };
+                let mut result = AnimationResult::default();
+                let _ = ctx.run(input, |ctx| {
+                    egui::CentralPanel::default().show(ctx, |ui| {
+                        ui.add_space(20_000.0);
+                        result = animate(ui, id, value, SLIDE_ANIM, |ui, _| {
+                            ui.label("Off-screen");
+                        });
+                    });
+                });
+                result
+            };
+
+            run_frame(0.0, 0);
+            // The rect is unknown on the first frame of the animation.
+            run_frame(0.25, 1);
+            assert!(has_transform(&ctx));
+            let result = run_frame(0.5, 1);
+            assert_eq!(result.run_state, RunState::OutSeg(0.5));
+            assert!(!has_transform(&ctx));
+            let result = run_frame(0.875, 1);
+            assert_eq!(result.run_state, RunState::InSeg(0.25));
+            assert!(!has_transform(&ctx));
+            let result = run_frame(1.5, 1);
+            assert!(result.just_finished);
+        }
+
+        #[test]
+        fn test_next_repaint_after() {
+            let ctx = egui::Context::default();
+            let next_repaint_after = |time: f64, value: i32| {
+                let input = egui::RawInput {
+                    time: Some(time),
+                    ..Default::default()
+                };
+                let mut repaint_after = None;
+                let _ = ctx.run(input, |ctx| {
+                    egui::CentralPanel::default().show(ctx, |ui| {
+                        animate(ui, "test_anim", value, TEST_ANIM, |_, _| {});
+                        repaint_after = next_repaint_after(ui, "test_anim", TEST_ANIM);
+                    });
+                });
+                repaint_after
+            };
+
+            assert_eq!(next_repaint_after(0.0, 0), None);
+            let repaint_after = next_repaint_after(1.0, 1);
+            assert_eq!(repaint_after, Some(std::time::Duration::ZERO));
+
+            crate::set_max_fps(&ctx, Some(4.0));
+            let repaint_after = next_repaint_after(1.3, 1).unwrap();
+            assert!((repaint_after.as_secs_f64() - 0.2).abs() < 1e-6);
+            assert_eq!(next_repaint_after(2.5, 1), None);
+        }
+
+        #[test]
+        fn test_run_state_eased() {
+            const KEYFRAMES: &[(f32, f32)] = &[(0.0, 0.0), (0.5, 1.0), (1.0, 1.0)];
+            let mut eased_anim = TEST_ANIM;
+            eased_anim.in_seg = AnimationSegment::keyframes(0.5, KEYFRAMES, |_, _| {});
+            let ctx = egui::Context::default();
+            let run_states = |time: f64| {
+                let input = egui::RawInput {
+                    time: Some(time),
+                    ..Default::default()
+                };
+                let mut run_states = (RunState::None, RunState::None);
+                let _ = ctx.run(input, |ctx| {
+                    egui::CentralPanel::default().show(ctx, |ui| {
+                        animate(ui, "test_anim", time > 0.0, eased_anim, |_, _| {});
+                        run_states = (
+                            run_state(ui, "test_anim", eased_anim),
+                            run_state_eased(ui, "test_anim", eased_anim),
+                        );
+                    });
+                });
+                run_states
+            };
+
+            run_states(0.0);
+            run_states(1.0);
+            assert_eq!(
+                run_states(1.6),
+                (RunState::InSeg(0.2), RunState::InSeg(0.4))
+            );
+        }
+
+        #[test]
+        fn test_driver() {
+            let ctx = egui::Context::default();
+            let driver = |time: f64, value: i32| {
+                let input = egui::RawInput {
+                    time: Some(time),
+                    ..Default::default()
+                };
+                let mut driver = f32::NAN;
+                let _ = ctx.run(input, |ctx| {
+                    egui::CentralPanel::default().show(ctx, |ui| {
+                        animate(ui, "test_anim", value, TEST_ANIM, |_, _| {});
+                        driver = super::driver(ui, "test_anim", TEST_ANIM);
+                    });
+                });
+                driver
+            };
+
+            assert_eq!(driver(0.0, 0), 0.0);
+            assert_eq!(driver(1.0, 1), 0.0);
+            assert_eq!(driver(1.25, 1), 0.5);
+            assert_eq!(driver(1.5, 1), 1.0);
+            assert_eq!(driver(1.75, 1), 0.5);
+            assert_eq!(driver(2.0, 1), 0.0);
+            assert_eq!(driver(2.5, 1), 0.0);
+        }
+
+        #[test]
+        fn test_animate_by_frames() {
+            let ctx = egui::Context::default();
+            let run_state = |value: i32| {
+                let mut run_state = RunState::None;
+                let _ = ctx.run(Default::default(), |ctx| {
+                    egui::CentralPanel::default().show(ctx, |ui| {
+                        run_state =
+                            animate_by_frames(ui, "test_anim", value, 2, 4, TEST_ANIM, |_, _| {})
+                                .run_state;
+                    });
+                });
+                run_state
+            };
+
+            assert_eq!(run_state(0), RunState::None);
+            assert_eq!(run_state(1), RunState::OutSeg(0.0));
+            assert_eq!(run_state(1), RunState::OutSeg(0.5));
+            assert_eq!(run_state(1), RunState::InSeg(0.0));
+            assert_eq!(run_state(1), RunState::InSeg(0.25));
+            assert_eq!(run_state(1), RunState::InSeg(0.5));
+            assert_eq!(run_state(1), RunState::InSeg(0.75));
+            assert_eq!(run_state(1), RunState::None);
+            assert_eq!(run_state(1), RunState::None);
+            assert_eq!(run_state(2), RunState::OutSeg(0.0));
+        }
+
+        #[test]
+        fn test_animate_by_frames_memory() {
+            let ctx = egui::Context::default();
+            let id = egui::Id::new("test_anim");
+            let run_frame = |value: i32| {
+                let mut state = (RunState::None, None, None);
+                let _ = ctx.run(Default::default(), |ctx| {
+                    egui::CentralPanel::default().show(ctx, |ui| {
+                        let result = animate_by_frames(ui, id, value, 4, 2, TEST_ANIM, |_, _| {});
+                        let stats = crate::animation_stats(ui, id).map(|stats| stats.frames);
+                        state = (result.run_state, mem::get_start_time(ui, id), stats);
+                    });
+                });
+                state
+            };
+
+            run_frame(0);
+            run_frame(1);
+            // Frame counts are not read as seconds, and frames are recorded.
+            assert_eq!(run_frame(1), (RunState::OutSeg(0.25), None, Some(2)));
+            assert!(any_animation_running(&ctx));
+
+            // Reverted values play backward.
+            assert_eq!(run_frame(0).0, RunState::InSeg(0.5));
+            assert_eq!(run_frame(0).0, RunState::InSeg(0.75));
+
+            // Removed animations settle.
+            let _ = ctx.run(Default::default(), |_| {});
+            assert!(!any_animation_running(&ctx));
+            assert_eq!(run_frame(0).0, RunState::None);
+        }
+
+        #[test]
+        fn test_animation_enabled() {
+            let ctx = egui::Context::default();
+            let id = egui::Id::new("test_anim");
+            // Whether any memory of the animation is kept.
+            let has_memory = || {
+                let mut has_memory = true;
+                let _ = ctx.run(Default::default(), |ctx| {
+                    egui::CentralPanel::default().show(ctx, |ui| {
+                        has_memory = mem::get_start_time(ui, id).is_some()
+                            || mem::get_clock(ui, id).is_some()
+                            || mem::get_target_value::<i32>(ui, id).is_some()
+                            || mem::get_rect(ui, id).is_some()
+                            || mem::get_mark(ui, id).is_some()
+                            || mem::get_stats(ui, id).is_some();
+                    });
+                });
+                has_memory
+            };
+
+            run_frame(&ctx, 0.0, 0, TEST_ANIM);
+            assert!(run_frame(&ctx, 1.0, 1, TEST_ANIM).run_state.is_running());
+
+            // Disabling mid-flight finishes the animation, clearing its memory.
+            crate::set_animation_enabled(&ctx, id, false);
+            let result = run_frame(&ctx, 1.25, 1, TEST_ANIM);
+            assert_eq!(result.run_state, RunState::None);
+            assert!(result.just_finished);
+            assert!(running_animations(&ctx).is_empty());
+            assert!(!has_memory());
+            assert_eq!(
+                run_frame(&ctx, 1.5, 2, TEST_ANIM),
+                AnimationResult::default()
+            );
+            assert!(!has_memory());
 
-            assert_eq!(state.in_elapsed(), Some(0.0));
-            state.current_time = 2.0;
-            assert_eq!(state.in_elapsed(), Some(0.0));
-            state.current_time = 3.0;
-            assert_eq!(state.in_elapsed(), Some(0.5));
-            state.current_time = 4.0;
-            assert_eq!(state.in_elapsed(), None);
-            state.current_time = 5.0;
-            assert_eq!(state.in_elapsed(), None);
+            crate::set_animation_enabled(&ctx, id, true);
+            assert_eq!(
+                run_frame(&ctx, 2.0, 2, TEST_ANIM),
+                AnimationResult::default()
+            );
+            assert!(run_frame(&ctx, 2.5, 3, TEST_ANIM).run_state.is_running());
         }
+
+        #[test]
+        fn test_animate_progress_disabled() {
+            let ctx = egui::Context::default();
+            let opacity = || {
+                let mut opacity = f32::NAN;
+                let _ = ctx.run(Default::default(), |ctx| {
+                    egui::CentralPanel::default().show(ctx, |ui| {
+                        animate_progress(
+                            ui,
+                            "test_anim",
+                            0.25,
+                            |ui, n| ui.set_opacity(n),
+                            |ui| {
+                                opacity = ui.opacity();
+                            },
+                        );
+                    });
+                });
+                opacity
+            };
+
+            assert_eq!(opacity(), 0.25);
+            crate::set_animation_enabled(&ctx, "test_anim", false);
+            assert_eq!(opacity(), 1.0);
+        }
+
+        #[test]
+        fn test_disabled_while_animating() {
+            let ctx = egui::Context::default();
+            let enabled = |time: f64, value: i32| {
+                let input = egui::RawInput {
+                    time: Some(time),
+                    ..Default::default()
+                };
+                let mut enabled = false;
+                let _ = ctx.run(input, |ctx| {
+                    egui::CentralPanel::default().show(ctx, |ui| {
+                        animate(ui, "test_anim", value, TEST_ANIM, |_, _| {});
+                        disabled_while_animating(ui, "test_anim", TEST_ANIM, |ui| {
+                            enabled = ui.is_enabled();
+                        });
+                    });
+                });
+                enabled
+            };
+
+            assert!(enabled(0.0, 0));
+            assert!(!enabled(1.0, 1));
+            assert!(!enabled(1.75, 1));
+            assert!(enabled(2.0, 1));
+        }
+
+        #[test]
+        fn test_previous_value() {
+            let ctx = egui::Context::default();
+            let previous_value = |time: f64, value: i32| {
+                let input = egui::RawInput {
+                    time: Some(time),
+                    ..Default::default()
+                };
+                let mut previous = None;
+                let _ = ctx.run(input, |ctx| {
+                    egui::CentralPanel::default().show(ctx, |ui| {
+                        animate(ui, "test_anim", value, TEST_ANIM, |_, _| {});
+                        previous = super::previous_value::<i32>(ui, "test_anim");
+                    });
+                });
+                previous
+            };
+
+            assert_eq!(previous_value(0.0, 0), None);
+            assert_eq!(previous_value(1.0, 1), Some(0));
+            assert_eq!(previous_value(1.75, 1), Some(0));
+            assert_eq!(previous_value(2.0, 1), None);
+            assert_eq!(previous_value(2.5, 1), None);
+        }
+
+        #[test]
+        fn test_partially_cleared_memory() {
+            let ctx = egui::Context::default();
+            let id = egui::Id::new("test_anim");
+            // Only the start value is cleared.
+            run_frame(&ctx, 0.0, 0, TEST_ANIM);
+            run_frame(&ctx, 1.0, 1, TEST_ANIM);
+            let start_value = ctx.memory_mut(|m| m.data.remove_temp::<i32>(id.with("start_value")));
+            assert_eq!(start_value, Some(0));
+            let result = run_frame(&ctx, 1.25, 1, TEST_ANIM);
+            assert_eq!(result.run_state, RunState::None);
+            run_frame(&ctx, 1.5, 1, TEST_ANIM);
+            let result = run_frame(&ctx, 2.0, 2, TEST_ANIM);
+            assert!(result.just_started);
+            assert_eq!(result.run_state, RunState::OutSeg(0.0));
+
+            // Only the start time is cleared.
+            let start_time = ctx.memory_mut(|m| m.data.remove_temp::<f64>(id.with("start_time")));
+            assert_eq!(start_time, Some(2.0));
+            let result = run_frame(&ctx, 2.25, 2, TEST_ANIM);
+            assert!(result.just_started);
+            assert_eq!(result.run_state, RunState::OutSeg(0.0));
+            let result = run_frame(&ctx, 2.5, 2, TEST_ANIM);
+            assert_eq!(result.run_state, RunState::OutSeg(0.5));
+        }
+
+        #[test]
+        fn test_opacity_scoped() {
+            const FADE_ANIM: Animation = Animation::new(
+                1.0,
+                |ui, normal| ui.set_opacity(1.0 - normal),
+                |ui, normal| ui.set_opacity(normal),
+            );
+            let ctx = egui::Context::default();
+            let opacities = |time: f64, value: i32| {
+                let input = egui::RawInput {
+                    time: Some(time),
+                    ..Default::default()
+                };
+                let mut opacities = (0.0, 0.0);
+                let _ = ctx.run(input, |ctx| {
+                    egui::CentralPanel::default().show(ctx, |ui| {
+                        animate(ui, "test_anim", value, FADE_ANIM, |ui, _| {
+                            opacities.0 = ui.opacity();
+                            ui.label("Faded");
+                        });
+                        // A sibling drawn after the animated block.
+                        opacities.1 = ui.opacity();
+                        ui.label("Opaque");
+                    });
+                });
+                opacities
+            };
+
+            opacities(0.0, 0);
+            assert_eq!(opacities(1.0, 1), (1.0, 1.0));
+            assert_eq!(opacities(1.25, 1), (0.5, 1.0));
+            assert_eq!(opacities(1.75, 1), (0.5, 1.0));
+        }
+
+        #[test]
+        fn test_nested_layers() {
+            fn slide(ui: &mut egui::Ui, normal: f32) {
+                ui.ctx().set_transform_layer(
+                    ui.layer_id(),
+                    egui::emath::TSTransform::from_translation(egui::vec2(normal + 1.0, 0.0)),
+                );
+            }
+            const SHORT_ANIM: Animation = Animation::new(1.0, slide, slide);
+            const LONG_ANIM: Animation = Animation::new(2.0, slide, slide);
+            let ctx = egui::Context::default();
+            let ids = ["outer_anim", "inner_anim_a", "inner_anim_b"].map(egui::Id::new);
+            let run_frame = |time: f64, value: i32| {
+                let input = egui::RawInput {
+                    time: Some(time),
+                    ..Default::default()
+                };
+                let mut layer_ids = [egui::LayerId::background(); 3];
+                let _ = ctx.run(input, |ctx| {
+                    egui::CentralPanel::default().show(ctx, |ui| {
+                        animate(ui, ids[0], value, SHORT_ANIM, |ui, _| {
+                            layer_ids[0] = ui.layer_id();
+                            animate(ui, ids[1], value, SHORT_ANIM, |ui, _| {
+                                layer_ids[1] = ui.layer_id();
+                            });
+                            animate(ui, ids[2], value, LONG_ANIM, |ui, _| {
+                                layer_ids[2] = ui.layer_id();
+                            });
+                        });
+                    });
+                });
+                layer_ids
+            };
+            let has_transforms =
+                || ids.map(|id| ctx.memory(|m| m.to_global.keys().any(|layer| layer.id == id)));
+
+            run_frame(0.0, 0);
+            run_frame(1.0, 1);
+            // Nested animations run on distinct layers, with independent transforms.
+            let layer_ids = run_frame(1.25, 1);
+            assert_eq!(layer_ids.map(|layer_id| layer_id.id), ids);
+            assert_eq!(has_transforms(), [true, true, true]);
+
+            // Transforms are cleared as each animation completes.
+            run_frame(2.0, 1);
+            assert_eq!(has_transforms(), [false, false, true]);
+            run_frame(3.0, 1);
+            assert_eq!(has_transforms(), [false, false, false]);
+        }
+
+        #[test]
+        fn test_cleanup_after_completion() {
+            fn slide(ui: &mut egui::Ui, normal: f32) {
+                ui.ctx().set_transform_layer(
+                    ui.layer_id(),
+                    egui::emath::TSTransform::from_translation(egui::vec2(normal + 1.0, 0.0)),
+                );
+            }
+            const SLIDE_ANIM: Animation = Animation::new(1.0, slide, slide);
+            let ctx = egui::Context::default();
+            let id = egui::Id::new("test_anim");
+            let layer_ids = [id, id.with(OVERLAP_SUFFIX)];
+            let has_transform = |ctx: &egui::Context| {
+                ctx.memory(|m| m.to_global.keys().any(|l| layer_ids.contains(&l.id)))
+            };
+
+            run_frame(&ctx, 0.0, 0, SLIDE_ANIM);
+            run_frame(&ctx, 0.25, 1, SLIDE_ANIM);
+            run_frame(&ctx, 1.0, 1, SLIDE_ANIM);
+            assert!(has_transform(&ctx));
+            let result = run_frame(&ctx, 1.5, 1, SLIDE_ANIM);
+            assert!(result.just_finished);
+
+            assert!(!has_transform(&ctx));
+            let _ = ctx.run(Default::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    assert_eq!(mem::get_start_time(ui, id), None);
+                    assert_eq!(mem::get_start_value::<i32>(ui, id), None);
+                    assert_eq!(mem::get_clock(ui, id), None);
+                });
+            });
+        }
+    }
+
+    mod run_state {
+        use super::*;
+
+        #[test]
+        fn test_normal_helpers() {
+            assert_eq!(RunState::OutSeg(0.25).normal(), Some(0.25));
+            assert_eq!(RunState::OutSeg(0.25).out_inverse(), Some(0.75));
+            assert_eq!(RunState::OutSeg(0.25).presence(), 0.75);
+            assert_eq!(RunState::InSeg(0.25).out_inverse(), None);
+            assert_eq!(RunState::InSeg(0.25).presence(), 0.25);
+            assert_eq!(RunState::Hold(0.25).presence(), 0.0);
+            assert_eq!(RunState::None.normal(), None);
+            assert_eq!(RunState::None.presence(), 1.0);
+        }
+
+        #[test]
+        fn test_signed_progress() {
+            assert_eq!(RunState::OutSeg(0.0).signed_progress(), Some(-1.0));
+            assert_eq!(RunState::OutSeg(0.75).signed_progress(), Some(-0.25));
+            assert_eq!(RunState::OutSeg(1.0).signed_progress(), Some(0.0));
+            assert_eq!(RunState::Hold(0.5).signed_progress(), Some(0.0));
+            assert_eq!(RunState::InSeg(0.0).signed_progress(), Some(0.0));
+            assert_eq!(RunState::InSeg(1.0).signed_progress(), Some(1.0));
+            assert_eq!(RunState::None.signed_progress(), None);
+        }
+
+        #[test]
+        fn test_progress() {
+            let animation = Animation::new(2.0, |_, _| {}, |_, _| {}).with_overlap(0.5);
+
+            assert_eq!(RunState::OutSeg(0.5).progress(&animation), Some(1.0 / 3.0));
+            assert_eq!(RunState::InSeg(1.0).progress(&animation), Some(1.0));
+            assert_eq!(RunState::None.progress(&animation), None);
+        }
+    }
+
+    mod animation_state {
+        use super::*;
+
+        const TEST_ANIM_STATE: AnimationState = AnimationState::new(
+            1.0,
+            1.0,
+            Animation::from_segments(
+                crate::AnimationSegment::new(1.5, |_, _| {}),
+                crate::AnimationSegment::new(1.5, |_, _| {}),
+            ),
+        );
+
         #[test]
-        fn test_in_elapsed_normal() {
+        fn test_repaint_after() {
+            const KEYFRAMES: &[(f32, f32)] = &[(0.0, 0.0), (0.5, 1.0), (1.0, 1.0)];
             let mut state = TEST_ANIM_STATE;
+            state.animation.in_seg = crate::AnimationSegment::keyframes(1.5, KEYFRAMES, |_, _| {});
+
+            assert_eq!(state.repaint_after(), 0.0);
+            state.clock.current_time = 3.0;
+            assert_eq!(state.repaint_after(), 0.0);
+            state.clock.current_time = 3.5;
+            assert_eq!(state.repaint_after(), 0.5);
+        }
+
+        /// Step the clock of the given `state` from the start time by `dt` seconds each
+        /// frame, collecting the `RunState` of each frame until the animation finishes.
+        fn collect_run_states(state: &AnimationState, dt: f64) -> Vec<RunState> {
+            let mut state = AnimationState::from_clock(state.clock, state.animation);
+            let mut run_states = Vec::new();
+            state.clock.current_time = state.clock.start_time;
+            loop {
+                let run_state = state.run_state();
+                run_states.push(run_state.clone());
+                if !run_state.is_running() {
+                    return run_states;
+                }
+                state.clock.current_time += dt;
+            }
+        }
 
-            assert_eq!(state.in_elapsed_normal(), Some(0.0));
-            state.current_time = 2.0;
-            assert_eq!(state.in_elapsed_normal(), Some(0.0));
-            state.current_time = 3.25;
-            assert_eq!(state.in_elapsed_normal(), Some(0.5));
-            state.current_time = 4.0;
-            assert_eq!(state.in_elapsed_normal(), None);
-            state.current_time = 5.0;
-            assert_eq!(state.in_elapsed_normal(), None);
+        #[test]
+        fn test_run_state_order() {
+            // The position of each variant in the expected order of an animation.
+            let order = |run_state: &RunState| match run_state {
+                RunState::OutSeg(_) => 0,
+                RunState::Hold(_) => 1,
+                RunState::InSeg(_) => 2,
+                RunState::None => 3,
+            };
+            let mut states = [TEST_ANIM_STATE; 3];
+            states[1].clock = states[1].clock.with_overlap(0.5);
+            states[2].clock = states[2].clock.with_hold(0.5);
+
+            for state in &states {
+                for dt in [1.0 / 60.0, 1.0 / 144.0, 0.1, 0.7] {
+                    let run_states = collect_run_states(state, dt);
+
+                    assert!(run_states.first().is_some_and(RunState::is_out_seg));
+                    assert!(run_states.iter().any(RunState::is_in_seg));
+                    assert_eq!(run_states.last(), Some(&RunState::None));
+                    assert!(run_states.iter().map(order).is_sorted());
+                }
+            }
         }
     }
 }
@@ -0,0 +1,126 @@
+//! Frame timing statistics of animations, for diagnosing dropped frames.
+use crate::mem;
+
+/// Frame timing statistics of the latest animation of an id. See [`animation_stats`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnimationStats {
+    /// The number of frames the animation was rendered.
+    pub frames: u32,
+    /// The longest time (in seconds) between two frames of the animation.
+    pub max_dt: f32,
+    /// Whether the animation skipped to its end, exceeding the frame budget. See
+    /// [`set_frame_budget`](crate::set_frame_budget).
+    pub caught_up: bool,
+}
+
+impl AnimationStats {
+    /// Record a frame of the animation, taking `dt` since the prior frame. The `dt` of
+    /// the first frame is ignored, as it precedes the animation.
+    pub(crate) fn sample(&mut self, dt: f32, has_prior_frame: bool, caught_up: bool) {
+        self.frames += 1;
+        if has_prior_frame {
+            self.max_dt = self.max_dt.max(dt);
+        }
+        self.caught_up |= caught_up;
+    }
+}
+
+/// Get the frame timing statistics of the animation of the given `id`. Returns `None`
+/// if the id has not been animated.
+///
+/// Statistics are collected from the first frame of each animation, and kept after the
+/// animation finishes until the next animation of the id starts. Statistics of animations
+/// settled without finishing, such as those of removed widgets, are cleared. Useful for
+/// finding animations that drop frames.
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # const MY_ANIM: Animation = Animation::EMPTY;
+/// # let mut my_state: u32 = 0;
+/// #
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// let result = animate(ui, "my_anim", my_state, MY_ANIM, |ui, value| {
+///     // ...
+/// });
+///
+/// if result.just_finished
+///     && let Some(stats) = animation_stats(ui, "my_anim")
+/// {
+///     println!("{} frames, longest frame {:.3}s", stats.frames, stats.max_dt);
+/// }
+/// #
+/// # });
+/// # });
+/// ```
+pub fn animation_stats(ui: &egui::Ui, id: impl Into<egui::Id>) -> Option<AnimationStats> {
+    mem::get_stats(ui, id.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Animation, animate};
+
+    const TEST_ANIM: Animation = Animation::new(1.0, |_, _| {}, |_, _| {});
+
+    fn run_frame(ctx: &egui::Context, time: f64, value: i32) -> Option<AnimationStats> {
+        let mut stats = None;
+        let input = egui::RawInput {
+            time: Some(time),
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                animate(ui, "test_anim", value, TEST_ANIM, |_, _| {});
+                stats = animation_stats(ui, "test_anim");
+            });
+        });
+        stats
+    }
+
+    #[test]
+    fn test_animation_stats() {
+        let ctx = egui::Context::default();
+
+        assert_eq!(run_frame(&ctx, 0.0, 0), None);
+        run_frame(&ctx, 1.0, 1);
+        run_frame(&ctx, 1.1, 1);
+        run_frame(&ctx, 1.4, 1);
+        run_frame(&ctx, 1.7, 1);
+        let stats = run_frame(&ctx, 2.0, 1).unwrap();
+        assert_eq!(stats.frames, 5);
+        assert!((stats.max_dt - 0.3).abs() < 1e-3);
+        assert!(!stats.caught_up);
+
+        // Kept until the next animation starts.
+        assert_eq!(run_frame(&ctx, 2.5, 1), Some(stats));
+        crate::set_frame_budget(&ctx, Some(0.2));
+        let stats = run_frame(&ctx, 3.0, 2).unwrap();
+        assert_eq!(stats.frames, 1);
+        assert!(stats.caught_up);
+    }
+
+    #[test]
+    fn test_settled_stats() {
+        let ctx = egui::Context::default();
+
+        run_frame(&ctx, 0.0, 0);
+        assert!(run_frame(&ctx, 1.0, 1).is_some());
+
+        // Skip the animation for a frame, settling it.
+        let _ = ctx.run(Default::default(), |_| {});
+        let _ = ctx.run(Default::default(), |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                assert_eq!(animation_stats(ui, "test_anim"), None);
+            });
+        });
+    }
+}
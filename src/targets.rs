@@ -0,0 +1,184 @@
+//! Animation targets set from other threads.
+use std::any::Any;
+use std::sync::{Arc, Mutex};
+
+use crate::{Animation, AnimationResult, animate, mem};
+
+/// A thread-safe queue of animation target values, set from any thread and animated on
+/// the UI thread with [`animate_targeted`].
+///
+/// Animation state lives within the memory of the [`egui::Context`], and [`animate`]
+/// must be called on the UI thread. The `Send + Sync` bounds of animated values are
+/// required by the `egui` memory, rather than for sharing animations across threads.
+/// `AnimationTargets` bridges triggers originating off the UI thread (e.g. network events
+/// or timers): setting a target requests a repaint, and [`animate_targeted`] drains the
+/// latest pending target of its id into the `egui` memory each frame, animating towards
+/// it. Cloned targets share the same pending values.
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # const MY_ANIM: Animation = Animation::EMPTY;
+/// # let ctx = egui::Context::default();
+/// let targets = AnimationTargets::<u32>::new(&ctx);
+///
+/// // Set the target from a background thread.
+/// let background = targets.clone();
+/// std::thread::spawn(move || background.set_target("messages", 3))
+///     .join()
+///     .unwrap();
+///
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// animate_targeted(ui, "messages", &targets, MY_ANIM, |ui, count| {
+///     ui.label(format!("{count} new messages"));
+/// });
+/// #
+/// # });
+/// # });
+/// ```
+pub struct AnimationTargets<T> {
+    ctx: egui::Context,
+    targets: Arc<Mutex<egui::IdMap<T>>>,
+}
+
+impl<T> Clone for AnimationTargets<T> {
+    fn clone(&self) -> Self {
+        Self {
+            ctx: self.ctx.clone(),
+            targets: self.targets.clone(),
+        }
+    }
+}
+
+impl<T: Clone> AnimationTargets<T> {
+    /// Create a new `AnimationTargets`, repainting the given [`egui::Context`] when a
+    /// target is set.
+    pub fn new(ctx: &egui::Context) -> Self {
+        Self {
+            ctx: ctx.clone(),
+            targets: Default::default(),
+        }
+    }
+
+    /// Set the pending target value of the animation of the given `id`, replacing any
+    /// prior pending target. Callable from any thread.
+    pub fn set_target(&self, id: impl Into<egui::Id>, value: T) {
+        self.lock().insert(id.into(), value);
+        self.ctx.request_repaint();
+    }
+
+    /// Get the pending target value of the animation of the given `id`. Returns `None` if
+    /// no target has been set since the last frame drained it.
+    pub fn pending(&self, id: impl Into<egui::Id>) -> Option<T> {
+        self.lock().get(&id.into()).cloned()
+    }
+
+    /// Take the pending target value of the animation of the given `id`.
+    fn take(&self, id: egui::Id) -> Option<T> {
+        self.lock().remove(&id)
+    }
+
+    /// Lock the targets, recovering from a poisoned lock.
+    fn lock(&self) -> std::sync::MutexGuard<'_, egui::IdMap<T>> {
+        self.targets
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Create an animation that transitions between changes of the target value set in the
+/// given `targets` for the `id`, such as from a background thread. See
+/// [`AnimationTargets`] and [`animate`].
+///
+/// Drains the pending target of the `id` on each call, keeping the latest target in the
+/// `egui` memory. Animates the default value until a target is set.
+pub fn animate_targeted<T: 'static + Any + Clone + Send + Sync + Default + PartialEq, R>(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    targets: &AnimationTargets<T>,
+    animation: impl Into<Animation>,
+    add_contents: impl FnOnce(&mut egui::Ui, T) -> R,
+) -> AnimationResult {
+    let id: egui::Id = id.into();
+    let value = match targets.take(id) {
+        Some(value) => mem::insert_shared_target(ui, id, value),
+        None => mem::get_shared_target(ui, id).unwrap_or_default(),
+    };
+
+    animate(ui, id, value, animation, add_contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RunState;
+
+    #[test]
+    fn test_set_target_from_thread() {
+        let ctx = egui::Context::default();
+        let targets = AnimationTargets::<u32>::new(&ctx);
+        let run_frame = |time: f64| {
+            let mut result = AnimationResult::default();
+            let input = egui::RawInput {
+                time: Some(time),
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    result = animate_targeted(
+                        ui,
+                        "test_anim",
+                        &targets,
+                        Animation::new(1.0, |_, _| {}, |_, _| {}),
+                        |_, _| {},
+                    );
+                });
+            });
+            result
+        };
+
+        assert_eq!(run_frame(0.0), AnimationResult::default());
+        let background = targets.clone();
+        std::thread::spawn(move || background.set_target("test_anim", 1))
+            .join()
+            .unwrap();
+        assert_eq!(targets.pending("test_anim"), Some(1));
+        assert!(ctx.has_requested_repaint());
+        assert_eq!(run_frame(1.0).run_state, RunState::OutSeg(0.0));
+    }
+
+    #[test]
+    fn test_drain_targets() {
+        let ctx = egui::Context::default();
+        let targets = AnimationTargets::<u32>::new(&ctx);
+        let run_frame = |time: f64| {
+            let mut value = 0;
+            let input = egui::RawInput {
+                time: Some(time),
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    animate_targeted(ui, "test_anim", &targets, Animation::EMPTY, |_, v| {
+                        value = v;
+                    });
+                });
+            });
+            value
+        };
+
+        targets.set_target("test_anim", 1);
+        assert_eq!(run_frame(0.0), 1);
+        assert_eq!(targets.pending("test_anim"), None);
+        assert_eq!(run_frame(1.0), 1);
+
+        targets.set_target("test_anim", 2);
+        targets.set_target("test_anim", 3);
+        assert_eq!(run_frame(2.0), 3);
+        assert_eq!(run_frame(3.0), 3);
+    }
+}
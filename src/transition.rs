@@ -0,0 +1,284 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::state::animate_with;
+use crate::{Animation, AnimationResult, default_animation};
+
+/// A value that defines its own [`Animation`] for transitions between values.
+///
+/// Encapsulates the choice of animation (e.g. a *forward* or *back* animation) within
+/// the type, rather than at every call site. See [`animate_transition`].
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// # const SLIDE_FADE_LEFT: Animation = Animation::EMPTY;
+/// # const SLIDE_FADE_RIGHT: Animation = Animation::EMPTY;
+/// #[derive(Default, Clone, Copy, PartialEq, PartialOrd)]
+/// enum MyMenu {
+///     #[default]
+///     MainMenu,
+///     Options,
+/// }
+///
+/// impl Transition for MyMenu {
+///     fn animation(from: &Self, to: &Self) -> Animation {
+///         // Slide left deeper into the menu, and right on the way back.
+///         match from < to {
+///             true => SLIDE_FADE_LEFT,
+///             false => SLIDE_FADE_RIGHT,
+///         }
+///     }
+/// }
+/// ```
+pub trait Transition {
+    /// Get the `Animation` for the transition from the prior value `from`, to the new
+    /// value `to`.
+    fn animation(from: &Self, to: &Self) -> Animation;
+}
+
+/// Create an animation that transitions between changes of the given `value`, using
+/// the [`Animation`] defined by the [`Transition`] implementation of the value.
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # #[derive(Default, Clone, Copy, PartialEq)]
+/// # enum MyMenu {
+/// #     #[default]
+/// #     MainMenu,
+/// #     Options,
+/// # }
+/// # impl Transition for MyMenu {
+/// #     fn animation(_: &Self, _: &Self) -> Animation {
+/// #         Animation::EMPTY
+/// #     }
+/// # }
+/// let mut menu_state = MyMenu::MainMenu;
+///
+/// # let ctx = egui::Context::default();
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// animate_transition(ui, "menu_anim", menu_state, |ui, menu| match menu {
+///     MyMenu::MainMenu => {
+///         if ui.button("Options").clicked() {
+///             menu_state = MyMenu::Options;
+///         }
+///     }
+///     MyMenu::Options => {
+///         if ui.button("Back").clicked() {
+///             menu_state = MyMenu::MainMenu;
+///         }
+///     }
+/// });
+/// # });
+/// # });
+/// ```
+pub fn animate_transition<
+    T: 'static + Any + Clone + Send + Sync + Default + PartialEq + Transition,
+    R,
+>(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    value: T,
+    add_contents: impl FnOnce(&mut egui::Ui, T) -> R,
+) -> AnimationResult {
+    animate_with(ui, id.into(), value, T::animation, add_contents, |_| {})
+}
+
+/// Create an animation that transitions between changes of the given `value`, using
+/// the [`Animation`] of the `table` for the `(from, to)` pair of the prior and new
+/// values. Pairs missing from the table use the default animation of the
+/// [`egui::Context`] (see [`crate::set_default_animation`]).
+///
+/// Declares the transitions of a state machine once, rather than setting the animation
+/// wherever the state changes. See [`Transition`] to define the animations with the
+/// type instead.
+///
+/// # Example
+/// ```
+/// # use std::collections::HashMap;
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # const SLIDE_FADE_LEFT: Animation = Animation::EMPTY;
+/// # const SLIDE_FADE_RIGHT: Animation = Animation::EMPTY;
+/// #[derive(Default, Clone, Copy, PartialEq, Eq, Hash)]
+/// enum MyMenu {
+///     #[default]
+///     MainMenu,
+///     Options,
+/// }
+///
+/// let table = HashMap::from([
+///     ((MyMenu::MainMenu, MyMenu::Options), SLIDE_FADE_LEFT),
+///     ((MyMenu::Options, MyMenu::MainMenu), SLIDE_FADE_RIGHT),
+/// ]);
+/// let mut menu_state = MyMenu::MainMenu;
+///
+/// # let ctx = egui::Context::default();
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// animate_state_machine(ui, "menu_anim", menu_state, &table, |ui, menu| match menu {
+///     MyMenu::MainMenu => {
+///         if ui.button("Options").clicked() {
+///             menu_state = MyMenu::Options;
+///         }
+///     }
+///     MyMenu::Options => {
+///         if ui.button("Back").clicked() {
+///             menu_state = MyMenu::MainMenu;
+///         }
+///     }
+/// });
+/// # });
+/// # });
+/// ```
+pub fn animate_state_machine<
+    T: 'static + Any + Clone + Send + Sync + Default + PartialEq + Eq + Hash,
+    R,
+>(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    value: T,
+    table: &HashMap<(T, T), Animation>,
+    add_contents: impl FnOnce(&mut egui::Ui, T) -> R,
+) -> AnimationResult {
+    let fallback = default_animation(ui.ctx());
+    let animation = |from: &T, to: &T| {
+        (table.get(&(from.clone(), to.clone()))).map_or(fallback, |animation| *animation)
+    };
+    animate_with(ui, id.into(), value, animation, add_contents, |_| {})
+}
+
+/// Create an animation that transitions between changes of the given ordered `value`,
+/// using the `up` animation when the value increases, and the `down` animation when it
+/// decreases. Values that can not be compared (such as `NaN`) use the `up` animation.
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// # const SLIDE_UP: Animation = Animation::EMPTY;
+/// # const SLIDE_DOWN: Animation = Animation::EMPTY;
+/// let mut count = 0u32;
+///
+/// # let ctx = egui::Context::default();
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// if ui.button("+").clicked() {
+///     count += 1;
+/// }
+/// animate_ordered(ui, "count_anim", count, SLIDE_UP, SLIDE_DOWN, |ui, count| {
+///     ui.label(count.to_string());
+/// });
+/// # });
+/// # });
+/// ```
+pub fn animate_ordered<T: 'static + Any + Clone + Send + Sync + Default + PartialOrd, R>(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    value: T,
+    up: impl Into<Animation>,
+    down: impl Into<Animation>,
+    add_contents: impl FnOnce(&mut egui::Ui, T) -> R,
+) -> AnimationResult {
+    let (up, down) = (up.into(), down.into());
+    let animation = |from: &T, to: &T| match to < from {
+        true => down,
+        false => up,
+    };
+    animate_with(ui, id.into(), value, animation, add_contents, |_| {})
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RunState, set_default_animation};
+
+    /// Run a single frame at the given `time`, returning the run state.
+    fn run_frame(
+        ctx: &egui::Context,
+        time: f64,
+        value: u32,
+        table: &HashMap<(u32, u32), Animation>,
+    ) -> RunState {
+        let mut run_state = RunState::None;
+        let input = egui::RawInput {
+            time: Some(time),
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                run_state =
+                    animate_state_machine(ui, "test_anim", value, table, |_, _| {}).run_state;
+            });
+        });
+        run_state
+    }
+
+    /// Run a single frame of [`animate_ordered`] at the given `time`, returning the run
+    /// state.
+    fn run_ordered_frame(ctx: &egui::Context, time: f64, value: f32) -> RunState {
+        let mut run_state = RunState::None;
+        let input = egui::RawInput {
+            time: Some(time),
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let up = Animation::new(1.0, |_, _| {}, |_, _| {});
+                let down = Animation::new(2.0, |_, _| {}, |_, _| {});
+                run_state = animate_ordered(ui, "test_anim", value, up, down, |_, _| {}).run_state;
+            });
+        });
+        run_state
+    }
+
+    #[test]
+    fn test_animate_ordered() {
+        let ctx = egui::Context::default();
+
+        run_ordered_frame(&ctx, 0.0, 0.0);
+        // Increases use the up animation.
+        run_ordered_frame(&ctx, 1.0, 1.0);
+        assert_eq!(run_ordered_frame(&ctx, 1.25, 1.0), RunState::OutSeg(0.5));
+        run_ordered_frame(&ctx, 2.0, 1.0);
+        run_ordered_frame(&ctx, 2.5, 1.0);
+
+        // Decreases use the down animation.
+        run_ordered_frame(&ctx, 3.0, -1.0);
+        assert_eq!(run_ordered_frame(&ctx, 3.25, -1.0), RunState::OutSeg(0.25));
+    }
+
+    #[test]
+    fn test_animate_state_machine() {
+        let ctx = egui::Context::default();
+        set_default_animation(&ctx, Animation::new(4.0, |_, _| {}, |_, _| {}));
+        let table = HashMap::from([
+            ((0, 1), Animation::new(1.0, |_, _| {}, |_, _| {})),
+            ((1, 0), Animation::new(2.0, |_, _| {}, |_, _| {})),
+        ]);
+
+        run_frame(&ctx, 0.0, 0, &table);
+        // Forward transitions use the (0, 1) animation.
+        run_frame(&ctx, 1.0, 1, &table);
+        assert_eq!(run_frame(&ctx, 1.25, 1, &table), RunState::OutSeg(0.5));
+        run_frame(&ctx, 2.0, 1, &table);
+        run_frame(&ctx, 2.5, 1, &table);
+
+        // Backward transitions use the (1, 0) animation.
+        run_frame(&ctx, 3.0, 0, &table);
+        assert_eq!(run_frame(&ctx, 3.25, 0, &table), RunState::OutSeg(0.25));
+        run_frame(&ctx, 5.0, 0, &table);
+        run_frame(&ctx, 5.5, 0, &table);
+
+        // Missing pairs use the default animation.
+        run_frame(&ctx, 6.0, 2, &table);
+        assert_eq!(run_frame(&ctx, 6.5, 2, &table), RunState::OutSeg(0.25));
+    }
+}
@@ -0,0 +1,112 @@
+//! Text revealed one character at a time.
+use crate::state::animate_with;
+use crate::{Animation, AnimationResult, RunState};
+
+/// Reveal the given `text` one character at a time over the given `duration` (in
+/// seconds), passing the revealed prefix to `add_contents`. The animation is triggered
+/// by changes of the `text`.
+///
+/// The prefix is sliced on `char` boundaries, so multi-byte UTF-8 text is never split
+/// within a character. Reverting to the prior text mid-reveal erases the text back out.
+///
+/// # Example
+/// ```
+/// # use egui;
+/// # use eframe;
+/// # use egui_animate::*;
+/// let message = "Hello, world!";
+///
+/// # let ctx = egui::Context::default();
+/// #
+/// # ctx.run(egui::RawInput::default(), |ctx| {
+/// # egui::CentralPanel::default().show(ctx, |ui| {
+/// #
+/// typewriter(ui, "message", message, 1.0, |ui, text| {
+///     ui.label(text);
+/// });
+/// #
+/// # });
+/// # });
+/// ```
+pub fn typewriter(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    text: &str,
+    duration: f32,
+    add_contents: impl FnOnce(&mut egui::Ui, &str),
+) -> AnimationResult {
+    let mut add_contents = Some(add_contents);
+    let normal = std::cell::Cell::new(1.0);
+
+    animate_with(
+        ui,
+        id.into(),
+        text.to_owned(),
+        |_, _| Animation::new_in(duration, |_, _| {}),
+        |ui, text| {
+            if let Some(add_contents) = add_contents.take() {
+                add_contents(ui, prefix(&text, normal.get()));
+            }
+        },
+        |state| {
+            normal.set(match state {
+                RunState::OutSeg(normal) => 1.0 - normal,
+                RunState::Hold(_) => 0.0,
+                RunState::InSeg(normal) => normal,
+                RunState::None => 1.0,
+            })
+        },
+    )
+}
+
+/// Get the prefix of the `text` with the given `normal` of its characters.
+fn prefix(text: &str, normal: f32) -> &str {
+    let len = text.chars().count();
+    let chars = (len as f32 * normal.clamp(0.0, 1.0)).floor() as usize;
+    match text.char_indices().nth(chars) {
+        Some((index, _)) => &text[..index],
+        None => text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run a single frame at the given `time`, returning the revealed text.
+    fn run_frame(ctx: &egui::Context, time: f64, text: &str) -> String {
+        let mut revealed = String::new();
+        let input = egui::RawInput {
+            time: Some(time),
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                typewriter(ui, "test_text", text, 1.0, |_, text| {
+                    revealed = text.to_owned();
+                });
+            });
+        });
+        revealed
+    }
+
+    #[test]
+    fn test_prefix() {
+        assert_eq!(prefix("héllo", 0.0), "");
+        assert_eq!(prefix("héllo", 0.4), "hé");
+        assert_eq!(prefix("héllo", 1.0), "héllo");
+        assert_eq!(prefix("日本語", 0.7), "日本");
+        assert_eq!(prefix("", 0.5), "");
+    }
+
+    #[test]
+    fn test_typewriter() {
+        let ctx = egui::Context::default();
+
+        assert_eq!(run_frame(&ctx, 0.0, "ab"), "ab");
+        assert_eq!(run_frame(&ctx, 1.0, "wxyz"), "");
+        assert_eq!(run_frame(&ctx, 1.5, "wxyz"), "wx");
+        assert_eq!(run_frame(&ctx, 2.0, "wxyz"), "wxyz");
+        assert_eq!(run_frame(&ctx, 3.0, "wxyz"), "wxyz");
+    }
+}
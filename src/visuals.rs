@@ -0,0 +1,149 @@
+//! Interpolation of `egui::Visuals` for theme transitions.
+use egui::{Color32, CornerRadius, Stroke, Visuals, style::WidgetVisuals};
+
+/// Set the [`Visuals`] of the `ui` to the interpolation of `from` and `to` for the
+/// given `normal`.
+///
+/// Colors, corner radii and strokes are interpolated. All other fields are taken from
+/// `from` for a `normal` below `0.5`, and from `to` otherwise.
+///
+/// # Example
+/// ```
+/// # use egui_animate::*;
+/// // Transition from the light theme to the dark theme over 0.5 seconds.
+/// const THEME_ANIM: Animation = Animation::new_in(0.5, |ui, normal| {
+///     interpolate_visuals(ui, normal, &egui::Visuals::light(), &egui::Visuals::dark());
+/// });
+/// ```
+pub fn interpolate_visuals(ui: &mut egui::Ui, normal: f32, from: &Visuals, to: &Visuals) {
+    *ui.visuals_mut() = lerp_visuals(from, to, normal);
+}
+
+/// Interpolate the colors, corner radii and strokes of the given [`Visuals`].
+fn lerp_visuals(from: &Visuals, to: &Visuals, t: f32) -> Visuals {
+    let mut visuals = match t < 0.5 {
+        true => from.clone(),
+        false => to.clone(),
+    };
+
+    visuals.override_text_color = lerp_option(from.override_text_color, to.override_text_color, t);
+    visuals.weak_text_color = lerp_option(from.weak_text_color, to.weak_text_color, t);
+    visuals.text_edit_bg_color = lerp_option(from.text_edit_bg_color, to.text_edit_bg_color, t);
+
+    let (from_widgets, to_widgets) = (&from.widgets, &to.widgets);
+    visuals.widgets.noninteractive = from_widgets
+        .noninteractive
+        .lerp(&to_widgets.noninteractive, t);
+    visuals.widgets.inactive = from_widgets.inactive.lerp(&to_widgets.inactive, t);
+    visuals.widgets.hovered = from_widgets.hovered.lerp(&to_widgets.hovered, t);
+    visuals.widgets.active = from_widgets.active.lerp(&to_widgets.active, t);
+    visuals.widgets.open = from_widgets.open.lerp(&to_widgets.open, t);
+
+    visuals.selection.bg_fill = from.selection.bg_fill.lerp(&to.selection.bg_fill, t);
+    visuals.selection.stroke = from.selection.stroke.lerp(&to.selection.stroke, t);
+
+    visuals.hyperlink_color = from.hyperlink_color.lerp(&to.hyperlink_color, t);
+    visuals.faint_bg_color = from.faint_bg_color.lerp(&to.faint_bg_color, t);
+    visuals.extreme_bg_color = from.extreme_bg_color.lerp(&to.extreme_bg_color, t);
+    visuals.code_bg_color = from.code_bg_color.lerp(&to.code_bg_color, t);
+    visuals.warn_fg_color = from.warn_fg_color.lerp(&to.warn_fg_color, t);
+    visuals.error_fg_color = from.error_fg_color.lerp(&to.error_fg_color, t);
+
+    visuals.window_corner_radius = from.window_corner_radius.lerp(&to.window_corner_radius, t);
+    visuals.window_shadow.color = from.window_shadow.color.lerp(&to.window_shadow.color, t);
+    visuals.window_fill = from.window_fill.lerp(&to.window_fill, t);
+    visuals.window_stroke = from.window_stroke.lerp(&to.window_stroke, t);
+    visuals.menu_corner_radius = from.menu_corner_radius.lerp(&to.menu_corner_radius, t);
+    visuals.panel_fill = from.panel_fill.lerp(&to.panel_fill, t);
+    visuals.popup_shadow.color = from.popup_shadow.color.lerp(&to.popup_shadow.color, t);
+    visuals.text_cursor.stroke = from.text_cursor.stroke.lerp(&to.text_cursor.stroke, t);
+
+    visuals
+}
+
+/// Interpolate optional colors, falling back to the nearest value if either is `None`.
+fn lerp_option(from: Option<Color32>, to: Option<Color32>, t: f32) -> Option<Color32> {
+    match (from, to) {
+        (Some(from), Some(to)) => Some(from.lerp(&to, t)),
+        _ if t < 0.5 => from,
+        _ => to,
+    }
+}
+
+/// Linear interpolation of visual properties.
+trait Lerp {
+    fn lerp(&self, to: &Self, t: f32) -> Self;
+}
+
+impl Lerp for f32 {
+    fn lerp(&self, to: &Self, t: f32) -> Self {
+        egui::lerp(*self..=*to, t)
+    }
+}
+
+impl Lerp for u8 {
+    fn lerp(&self, to: &Self, t: f32) -> Self {
+        (*self as f32).lerp(&(*to as f32), t).round() as u8
+    }
+}
+
+impl Lerp for Color32 {
+    fn lerp(&self, to: &Self, t: f32) -> Self {
+        self.lerp_to_gamma(*to, t)
+    }
+}
+
+impl Lerp for Stroke {
+    fn lerp(&self, to: &Self, t: f32) -> Self {
+        Stroke::new(self.width.lerp(&to.width, t), self.color.lerp(&to.color, t))
+    }
+}
+
+impl Lerp for CornerRadius {
+    fn lerp(&self, to: &Self, t: f32) -> Self {
+        CornerRadius {
+            nw: self.nw.lerp(&to.nw, t),
+            ne: self.ne.lerp(&to.ne, t),
+            sw: self.sw.lerp(&to.sw, t),
+            se: self.se.lerp(&to.se, t),
+        }
+    }
+}
+
+impl Lerp for WidgetVisuals {
+    fn lerp(&self, to: &Self, t: f32) -> Self {
+        WidgetVisuals {
+            bg_fill: self.bg_fill.lerp(&to.bg_fill, t),
+            weak_bg_fill: self.weak_bg_fill.lerp(&to.weak_bg_fill, t),
+            bg_stroke: self.bg_stroke.lerp(&to.bg_stroke, t),
+            corner_radius: self.corner_radius.lerp(&to.corner_radius, t),
+            fg_stroke: self.fg_stroke.lerp(&to.fg_stroke, t),
+            expansion: self.expansion.lerp(&to.expansion, t),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lerp_visuals_endpoints() {
+        let (light, dark) = (Visuals::light(), Visuals::dark());
+
+        assert_eq!(lerp_visuals(&light, &dark, 0.0), light);
+        assert_eq!(lerp_visuals(&light, &dark, 1.0), dark);
+    }
+
+    #[test]
+    fn test_lerp_visuals_midpoint() {
+        let (light, dark) = (Visuals::light(), Visuals::dark());
+        let visuals = lerp_visuals(&light, &dark, 0.5);
+
+        assert_eq!(
+            visuals.panel_fill,
+            light.panel_fill.lerp_to_gamma(dark.panel_fill, 0.5)
+        );
+        assert_eq!(visuals.dark_mode, dark.dark_mode);
+    }
+}